@@ -0,0 +1,36 @@
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day1;
+
+fn count_increments(depths: &[i32]) -> usize {
+    let mut increments = 0;
+
+    for i in 1..depths.len() {
+        if depths[i-1] < depths[i] {
+            increments += 1;
+        }
+    }
+    return increments;
+}
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+
+    fn parse(data: &str) -> Result<Vec<i32>, ParseError> {
+        let mut result: Vec<i32> = Vec::new();
+        for elem in data.split("\n") {
+            result.push(error::parse_number(1, data, elem)?);
+        }
+        return Ok(result);
+    }
+
+    fn part1(depths: &Vec<i32>) -> Result<Answer, NoAnswer> {
+        return Ok(count_increments(depths).into());
+    }
+
+    fn part2(depths: &Vec<i32>) -> Result<Answer, NoAnswer> {
+        let windows: Vec<i32> = depths.windows(3).map(|w| w.iter().sum()).collect();
+        return Ok(count_increments(&windows).into());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day10;
+
+pub enum LineStatus {
+    Corrupted(char),
+    Incomplete(Vec<char>),
+    Invalid,
+}
+
+pub fn check_line(line: &str) -> LineStatus {
+    let mut terminator_stack: Vec<char> = Vec::new();
+    for c in line.chars() {
+        match c {
+            '(' => terminator_stack.push(')'),
+            '[' => terminator_stack.push(']'),
+            '{' => terminator_stack.push('}'),
+            '<' => terminator_stack.push('>'),
+            _ => {
+                let expected = terminator_stack.pop();
+                if expected.is_none() {
+                    return LineStatus::Invalid;
+                } else if expected != Some(c) {
+                    return LineStatus::Corrupted(c);
+                }
+            }
+        }
+    }
+    return LineStatus::Incomplete(terminator_stack);
+}
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(data: &str) -> Result<Vec<String>, ParseError> {
+        for line in data.lines() {
+            if let Some((i, _)) = line.char_indices().find(|(_, c)| !"()[]{}<>".contains(*c)) {
+                return Err(ParseError::at(10, data, error::char_at(line, i), "Expected a bracket"));
+            }
+        }
+        return Ok(data.lines().map(|l| l.to_string()).collect());
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, NoAnswer> {
+        let mut syntax_errors: Vec<char> = Vec::new();
+        for line in lines {
+            if let LineStatus::Corrupted(c) = check_line(line) {
+                syntax_errors.push(c);
+            }
+        }
+
+        return Ok(syntax_errors.iter().map(| c | match c {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => panic!("Weird error"),
+        }).sum::<i64>().into());
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, NoAnswer> {
+        let mut scores: Vec<i64> = Vec::new();
+        for line in lines {
+            if let LineStatus::Incomplete(mut terminator_stack) = check_line(line) {
+                let mut score = 0;
+                while let Some(missing) = terminator_stack.pop() {
+                    score = score * 5 + match missing {
+                        ')' => 1,
+                        ']' => 2,
+                        '}' => 3,
+                        '>' => 4,
+                        _ => panic!("Weird error"),
+                    }
+                }
+                scores.push(score);
+            }
+        }
+        scores.sort();
+
+        return Ok(scores[scores.len()/2].into());
+    }
+}
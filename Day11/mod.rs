@@ -0,0 +1,78 @@
+use crate::error::ParseError;
+use crate::grid::{Grid, Neighbourhood, Pos};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day11;
+
+#[derive(Clone)]
+pub struct Dumbos {
+    energy_levels: Grid<u8>,
+}
+
+impl Dumbos {
+    fn reset_flashers(&mut self) {
+        for el in self.energy_levels.values_mut() {
+            if *el > 9 {
+                *el = 0;
+            }
+        }
+    }
+
+    //
+    // Advances one step, returns the number of flashes during it
+    //
+    pub fn step(&mut self) -> u32 {
+        let mut flashes = 0;
+        let mut flashers: Vec<Pos> = Vec::new();
+        for pos in self.energy_levels.positions() {
+            let el = &mut self.energy_levels[pos];
+            *el += 1;
+            if *el == 10 {
+                flashers.push(pos);
+                flashes += 1;
+            }
+        }
+        while let Some(flasher) = flashers.pop() {
+            for target in self.energy_levels.neighbours(flasher, Neighbourhood::All) {
+                let el = &mut self.energy_levels[target];
+                *el += 1;
+                if *el == 10 {
+                    flashers.push(target);
+                    flashes += 1;
+                }
+            }
+        }
+        self.reset_flashers();
+        return flashes;
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Dumbos;
+
+    fn parse(data: &str) -> Result<Dumbos, ParseError> {
+        let energy_levels = Grid::parse(11, data, "Expected a digit", |c| c.to_digit(10).map(|d| d as u8))?;
+        return Ok(Dumbos{energy_levels: energy_levels});
+    }
+
+    fn part1(dumbos: &Dumbos) -> Result<Answer, NoAnswer> {
+        let mut dumbos = dumbos.clone();
+        let mut flashes = 0;
+        for _step in 0 .. 100 {
+            flashes += dumbos.step();
+        }
+        return Ok(flashes.into());
+    }
+
+    fn part2(dumbos: &Dumbos) -> Result<Answer, NoAnswer> {
+        let mut dumbos = dumbos.clone();
+        let mut step = 0;
+        loop {
+            step += 1;
+            if dumbos.step() as usize == dumbos.energy_levels.len() {
+                break;
+            }
+        }
+        return Ok(step.into());
+    }
+}
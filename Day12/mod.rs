@@ -1,34 +1,31 @@
-use std::fs;
 use std::collections::HashMap;
 
-fn read_input() -> String {
-    let inputs =  fs::read_to_string("Day12/input").unwrap()
-                       .replace("\r", "");
-    
-    return inputs;
-}
+use crate::error::ParseError;
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day12;
 
 #[derive(Debug)] #[derive(PartialEq)]
-enum NodeType {
+pub enum NodeType {
     Big,
     Small,
     Start
 }
 
 #[derive(Debug)]
-struct Node {
+pub struct Node {
     name: String,
     node_type: NodeType,
     links: Vec<usize>,
 }
 
-#[derive(Debug)]
-struct NodeGraph {
+#[derive(Debug)] #[derive(Default)]
+pub struct NodeGraph {
     nodes : Vec<Node>,
-    names : HashMap<String, usize>, 
+    names : HashMap<String, usize>,
 }
 
-type Path = Vec<usize>;
+pub type Path = Vec<usize>;
 
 impl NodeGraph {
     pub fn new() -> NodeGraph {
@@ -39,13 +36,13 @@ impl NodeGraph {
     }
 
     pub fn add(&mut self, node: Node) -> usize {
-        let id = self.nodes.len(); 
+        let id = self.nodes.len();
         self.names.insert(node.name.clone(), id);
 
         self.nodes.push(node);
 
         return id;
-      
+
     }
 
     pub fn link(&mut self, node1: usize, node2: usize) {
@@ -69,46 +66,44 @@ impl NodeGraph {
         return self.names.get(name);
     }
 
-    pub fn explore(&self) -> Vec<Path> {
+    //
+    // Finds every path from start to end, small caves are visited at most once
+    // unless allow_double_visit lets a single one of them be visited twice
+    //
+    pub fn explore(&self, allow_double_visit: bool) -> Vec<Path> {
         type VisitedToExitIndex = HashMap<usize, Vec<usize>>;
 
         #[derive(Clone)]
         struct ExploringPath{ visisted: VisitedToExitIndex, path: Path, doube_visited: Option<usize> }
-        
+
         let mut expl_stack: Vec<ExploringPath> = Vec::new();
 
         let start = *self.get_id_by_name("start").unwrap();
-        expl_stack.push(ExploringPath{visisted: VisitedToExitIndex::new(), 
-            path: [start].to_vec(), 
+        expl_stack.push(ExploringPath{visisted: VisitedToExitIndex::new(),
+            path: [start].to_vec(),
             doube_visited: None});
 
         let mut exit_paths: Vec<Path> = Vec::new();
 
-        while expl_stack.len() > 0 {
-            let cur = expl_stack.pop().unwrap();
+        while let Some(cur) = expl_stack.pop() {
             let last = cur.path[cur.path.len() - 1];
             let cur_node = self.get(last);
 
             let empty_default = Vec::new();
-            let explored = cur.visisted.get(&last).unwrap_or(&empty_default); 
+            let explored = cur.visisted.get(&last).unwrap_or(&empty_default);
 
             //println!("Exploring: {:?}", cur.path.iter().map(|n| &self.get(*n).name ).collect::<Vec<&String>>());
             //println!("H. Visits: {:?}", explored.iter().map(|n| &self.get(*n).name ).collect::<Vec<&String>>());
-            
+
             if cur_node.name == "end" {
                 exit_paths.push(cur.path);
                 continue;
             }
 
-            for exit_index in 0 .. cur_node.links.len() {
-                let exit_id = cur_node.links[exit_index];
-                //if explored.contains(&exit_id) {
-                //    continue;
-                //}     
-                
+            for exit_id in cur_node.links.iter().cloned() {
                 let next = self.get(exit_id);
                 if  next.node_type != NodeType::Start && (!cur.visisted.contains_key(&exit_id) || (
-                    next.node_type != NodeType::Small || cur.doube_visited == None )) {
+                    next.node_type != NodeType::Small || (allow_double_visit && cur.doube_visited.is_none()) )) {
                     let mut next_path = cur.clone();
                     if next.node_type == NodeType::Small && cur.visisted.contains_key(&exit_id) {
                         next_path.doube_visited = Some(exit_id);
@@ -121,52 +116,58 @@ impl NodeGraph {
 
                     expl_stack.push(next_path);
                 }
-                
-            } 
+            }
         }
 
         return exit_paths;
     }
 }
 
-fn parse(data: &String) -> NodeGraph {
-    let mut nodes: NodeGraph = NodeGraph::new();
-
-    for l in data.lines() {
-        let mut prev: Option<usize> = None;
-        for name in l.split('-') {
-            if nodes.get_id_by_name(name) == None  {
-                let node_type = 
-                    if name == "start" {NodeType::Start}
-                    else if name.chars().all(|c| c.is_uppercase()) {NodeType::Big}
-                    else {NodeType::Small};
-
-                let new_node = Node{
-                    name: name.to_string(),
-                    node_type: node_type,
-                    links: Vec::new(),
-                    }; 
-                nodes.add(new_node);
+impl Solution for Day12 {
+    type Input = NodeGraph;
+
+    fn parse(data: &str) -> Result<NodeGraph, ParseError> {
+        let mut nodes: NodeGraph = NodeGraph::new();
+
+        for l in data.lines() {
+            let link: Vec<&str> = l.split('-').collect();
+            if link.len() != 2 || link.iter().any(|name| name.is_empty()) {
+                return Err(ParseError::at(12, data, l, "Expected \"<cave>-<cave>\""));
             }
-            let node_id = *nodes.get_id_by_name(name).unwrap();
-            if !prev.is_none() {
-                let prev_node_id = prev.unwrap();
-                nodes.link(prev_node_id, node_id);
+
+            let mut prev: Option<usize> = None;
+            for name in link {
+                if nodes.get_id_by_name(name).is_none() {
+                    let node_type =
+                        if name == "start" {NodeType::Start}
+                        else if name.chars().all(|c| c.is_uppercase()) {NodeType::Big}
+                        else {NodeType::Small};
+
+                    let new_node = Node{
+                        name: name.to_string(),
+                        node_type: node_type,
+                        links: Vec::new(),
+                        };
+                    nodes.add(new_node);
+                }
+                let node_id = *nodes.get_id_by_name(name).unwrap();
+                if let Some(prev_node_id) = prev {
+                    nodes.link(prev_node_id, node_id);
+                }
+                prev = Some(node_id);
             }
-            prev = Some(node_id);
         }
+        if nodes.get_id_by_name("start").is_none() {
+            return Err(ParseError::at_end(12, data, "Missing the start cave"));
+        }
+        return Ok(nodes);
     }
-    return nodes;
-}
 
-fn main() {
-    let inp = read_input();
-    let nodes = parse(&inp);
-
-    let paths = nodes.explore();
+    fn part1(nodes: &NodeGraph) -> Result<Answer, NoAnswer> {
+        return Ok(nodes.explore(false).len().into());
+    }
 
-    /*for path in &paths {
-        println!("{:?}", path.iter().map(|n| &nodes.get(*n).name ).collect::<Vec<&String>>());
-    }*/
-    println!("Solutions: {}", paths.len());
-}
\ No newline at end of file
+    fn part2(nodes: &NodeGraph) -> Result<Answer, NoAnswer> {
+        return Ok(nodes.explore(true).len().into());
+    }
+}
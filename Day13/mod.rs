@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+use regex::Regex;
+
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day13;
+
+type Position = (i32, i32);
+
+#[derive(Debug)]
+pub struct FoldInstruction {
+    axis: u8,
+    coordinate: i32,
+}
+
+#[derive(Debug)]
+pub struct TransparentPaper {
+    pub positions: HashSet<Position>,
+    pub instructions: Vec<FoldInstruction>,
+}
+
+fn do_fold(positions: &HashSet<Position>, instruction: &FoldInstruction) -> HashSet<Position> {
+    let mut new_positions: HashSet<Position> = HashSet::new();
+    for pos in positions {
+        let mut new_pos = *pos;
+        let new_axis = match instruction.axis {
+            0 => &mut new_pos.0,
+            1 => &mut new_pos.1,
+            _ => panic!(),
+        };
+        if *new_axis >= instruction.coordinate {
+            *new_axis -= 2*(*new_axis - instruction.coordinate);
+        }
+        new_positions.insert(new_pos);
+    }
+    return new_positions;
+}
+
+// There is at least one dot, folding keeps them all
+fn render(positions: &HashSet<Position>) -> String {
+    let min_x = positions.iter().map(|p| p.0 ).min().unwrap();
+    let max_x = positions.iter().map(|p| p.0 ).max().unwrap();
+    let min_y = positions.iter().map(|p| p.1 ).min().unwrap();
+    let max_y = positions.iter().map(|p| p.1 ).max().unwrap();
+
+    let mut res = String::new();
+    for y in min_y ..= max_y {
+        res.push('\n');
+        for x in min_x ..= max_x {
+            if positions.contains(&(x, y)) {
+                res.push('#');
+            } else {
+                res.push(' ');
+            }
+        }
+    }
+    return res;
+}
+
+impl Solution for Day13 {
+    type Input = TransparentPaper;
+
+    fn parse(data: &str) -> Result<TransparentPaper, ParseError> {
+        let pos_re = Regex::new(r"^(\d+),(\d+)$").unwrap();
+        let instr_re = Regex::new(r"^fold along (\w)=(\d+)$").unwrap();
+
+        let mut positions: HashSet<Position> = HashSet::new();
+        let mut instructions: Vec<FoldInstruction> = Vec::new();
+
+        let mut parsing_positions = true;
+        for l in data.lines() {
+            if l.is_empty() {
+                parsing_positions = false;
+            } else if parsing_positions {
+                let cap = pos_re.captures(l).ok_or_else(|| ParseError::at(13, data, l, "Expected \"x,y\""))?;
+                positions.insert((error::parse_number(13, data, cap.get(1).unwrap().as_str())?,
+                                  error::parse_number(13, data, cap.get(2).unwrap().as_str())?));
+            } else {
+                let cap = instr_re.captures(l).ok_or_else(|| ParseError::at(13, data, l, "Expected \"fold along <axis>=<n>\""))?;
+                let (axis, coordinate) = (cap.get(1).unwrap().as_str(),
+                                          error::parse_number(13, data, cap.get(2).unwrap().as_str())?);
+                let axis_no = match axis {
+                    "x" => 0,
+                    "y" => 1,
+                    _ => return Err(ParseError::at(13, data, axis, "Unknown axis")),
+                };
+                instructions.push(FoldInstruction{axis: axis_no, coordinate: coordinate});
+            }
+        }
+        if positions.is_empty() {
+            return Err(ParseError::at_end(13, data, "Missing dots"));
+        }
+        if instructions.is_empty() {
+            return Err(ParseError::at_end(13, data, "Missing fold instructions"));
+        }
+        return Ok(TransparentPaper{positions: positions, instructions: instructions});
+    }
+
+    fn part1(paper: &TransparentPaper) -> Result<Answer, NoAnswer> {
+        return Ok(do_fold(&paper.positions, &paper.instructions[0]).len().into());
+    }
+
+    fn part2(paper: &TransparentPaper) -> Result<Answer, NoAnswer> {
+        let mut folded = paper.positions.clone();
+        for insr in &paper.instructions {
+            folded = do_fold(&folded, insr);
+        }
+
+        return Ok(render(&folded).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_paper_without_dots() {
+        let error = Day13::parse("\nfold along x=2\n").unwrap_err();
+        assert_eq!((error.line, error.reason.as_str()), (3, "Missing dots"));
+    }
+}
@@ -0,0 +1,108 @@
+use std::cmp;
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::error::ParseError;
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day14;
+
+type Pairs = (char, char);
+
+type Elements = HashMap<Pairs, i64>;
+
+#[derive(Debug)]
+pub struct PairInsertionRule {
+    pair: (char, char),
+    insertee: char,
+}
+
+impl PairInsertionRule {
+    pub fn apply(&self, source: &Elements, dest: &mut Elements) -> bool {
+        let quant = *source.get(&self.pair).unwrap_or(&0);
+        if  quant > 0 {
+            dest.insert(self.pair, cmp::max(0, dest.get(&self.pair).unwrap_or(&1) - quant));
+            let new1 = (self.pair.0, self.insertee);
+            dest.insert(new1, dest.get(&new1).unwrap_or(&0) + quant);
+            let new2 = (self.insertee, self.pair.1);
+            dest.insert(new2, dest.get(&new2).unwrap_or(&0) + quant);
+
+            return true;
+        }
+        return false;
+    }
+}
+
+pub struct PolymerManual {
+    pub template: Elements,
+    // Pairs overlap, so every element is counted as the first of a pair except the last one
+    pub last_element: char,
+    pub rules: Vec<PairInsertionRule>,
+}
+
+fn polymerize(manual: &PolymerManual, steps: u32) -> i64 {
+    let mut polymer = manual.template.clone();
+
+    for _step in 0 .. steps {
+        let mut next_polymer = polymer.clone();
+        for rule in &manual.rules {
+            rule.apply(&polymer, &mut next_polymer);
+        }
+        polymer = next_polymer;
+    }
+
+    let mut quanitities: HashMap<char, i64> = HashMap::new();
+    quanitities.insert(manual.last_element, 1);
+    for (key, val) in polymer {
+        *quanitities.entry(key.0).or_insert(0) += val;
+    }
+
+    let least = quanitities.values().min().unwrap();
+    let most = quanitities.values().max().unwrap();
+
+    return most - least;
+}
+
+impl Solution for Day14 {
+    type Input = PolymerManual;
+
+    fn parse(data: &str) -> Result<PolymerManual, ParseError> {
+        let rule_re = Regex::new(r"^(\w\w) -> (\w)$").unwrap();
+
+        let mut elements: Elements = Elements::new();
+        let mut rules: Vec<PairInsertionRule> = Vec::new();
+
+        let mut parsing_template = true;
+
+        let mut last_c: Option<char> = None;
+        for l in data.lines() {
+            if l.is_empty() {
+                parsing_template = false;
+            } else if parsing_template {
+                for c in l.chars() {
+                    if let Some(prev) = last_c {
+                        *elements.entry((prev, c)).or_insert(0) += 1;
+                    }
+                    last_c = Some(c);
+                }
+            } else {
+                let cap = rule_re.captures(l).ok_or_else(|| ParseError::at(14, data, l, "Expected \"<pair> -> <element>\""))?;
+                let (pair, insertee) =  (&cap[1], &cap[2]);
+                rules.push(PairInsertionRule{pair: (pair.chars().next().unwrap(),
+                                                    pair.chars().nth(1).unwrap()),
+                                                    insertee: insertee.chars().next().unwrap()});
+            }
+        }
+        let last_element = last_c.ok_or_else(|| ParseError::at_end(14, data, "Missing the polymer template"))?;
+        return Ok(PolymerManual{template: elements, last_element: last_element, rules: rules});
+    }
+
+    fn part1(manual: &PolymerManual) -> Result<Answer, NoAnswer> {
+        return Ok(polymerize(manual, 10).into());
+    }
+
+    fn part2(manual: &PolymerManual) -> Result<Answer, NoAnswer> {
+        return Ok(polymerize(manual, 40).into());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::error::ParseError;
+use crate::grid::{Grid, Neighbourhood, Pos};
+use crate::search::{self, Path, Problem};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day15;
+
+#[derive(Debug)]
+pub struct RiskMap {
+    map: Grid<u8>,
+}
+
+impl RiskMap {
+    pub fn get_risk(&self, pos: Pos) -> u8 {
+        return self.map[pos];
+    }
+
+    pub fn end(&self) -> Pos {
+        return (self.map.width() - 1, self.map.height() - 1);
+    }
+
+    pub fn extend(&self, multiplier: i64) -> RiskMap{
+        let mut new_risks: Vec<u8> = Vec::new();
+        let (width, height) = (self.map.width(), self.map.height());
+
+        for y in 0 .. height * multiplier {
+            for x in 0 .. width * multiplier {
+                let risk = ((self.get_risk((x % width, y % height)) - 1) as i64 + x/width + y/height) % 9 + 1;
+                new_risks.push(risk as u8);
+            }
+        }
+        return RiskMap{map: Grid::from_cells(width * multiplier, new_risks)};
+    }
+
+    pub fn render(&self) -> String {
+        return self.map.render(|risk| (b'0' + risk) as char);
+    }
+
+    pub fn adjacents(&self, pos: Pos) -> Vec<Pos> {
+        return self.map.neighbours(pos, Neighbourhood::Orthogonal);
+    }
+
+    // From the top left to the bottom right, the risk of the start is not counted
+    pub fn safest(&self) -> Option<Path<Pos>> {
+        return search::astar(self, (0, 0)).path;
+    }
+
+    fn lowest_risk(&self) -> Result<Answer, NoAnswer> {
+        let path = self.safest().ok_or_else(|| NoAnswer::new(15, "The bottom right cannot be reached"))?;
+        return Ok(path.cost.into());
+    }
+}
+
+// Moving between the positions of the map, entering a position costs its risk
+impl Problem for RiskMap {
+    type State = Pos;
+    type Key = Pos;
+
+    fn key(&self, pos: &Pos) -> Pos {
+        return *pos;
+    }
+
+    fn neighbours(&self, pos: &Pos) -> Vec<(Pos, i64)> {
+        return self.adjacents(*pos).into_iter().map(|adj| (adj, self.get_risk(adj) as i64)).collect();
+    }
+
+    fn is_goal(&self, pos: &Pos) -> bool {
+        return *pos == self.end();
+    }
+
+    // Every position costs at least 1
+    fn heuristic(&self, pos: &Pos) -> i64 {
+        let end = self.end();
+        return (end.0 - pos.0) + (end.1 - pos.1);
+    }
+}
+
+impl Solution for Day15 {
+    type Input = RiskMap;
+
+    fn parse(data: &str) -> Result<RiskMap, ParseError> {
+        // Risks of 0 would make the heuristic overestimate
+        let map = Grid::parse(15, data, "Expected a risk from 1 to 9", |c| c.to_digit(10).filter(|d| *d > 0).map(|d| d as u8))?;
+        return Ok(RiskMap{map: map});
+    }
+
+    fn part1(risks: &RiskMap) -> Result<Answer, NoAnswer> {
+        return risks.lowest_risk();
+    }
+
+    fn part2(risks: &RiskMap) -> Result<Answer, NoAnswer> {
+        return risks.extend(5).lowest_risk();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_square_map() {
+        let risks = Day15::parse("19\n11\n91").unwrap();
+        assert_eq!(risks.get_risk((1, 0)), 9);
+        assert_eq!(Day15::part1(&risks), Ok(Answer::Int(3)));
+    }
+
+    #[test]
+    fn rejects_zero_risk() {
+        let error = Day15::parse("19\n10\n91").unwrap_err();
+        assert_eq!((error.line, error.column, error.reason.as_str()), (2, 2, "Expected a risk from 1 to 9"));
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::bignum::BigUint;
+
+use super::{describe_path, LengthType, Packet, PacketData, LENGTH_MODE_0_SIZE, LENGTH_MODE_1_SIZE};
+
+//
+// Why a packet tree built by hand has no transmission, the packet is located
+// by its path like for evaluation errors
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum EncodeError {
+    // Versions take 3 bits
+    Version{path: Vec<usize>, version: u8},
+    // Over 7, or not the one of the literal or operator
+    TypeId{path: Vec<usize>, type_id: u8},
+    // Too many sub-packets for the count and too many bits for the length
+    TooLarge{path: Vec<usize>, packets: usize, bits: usize},
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::Version{path, version} => write!(f, "Version {} of {} does not fit 3 bits", version, describe_path(path)),
+            EncodeError::TypeId{path, type_id} => write!(f, "Type id {} does not match {}", type_id, describe_path(path)),
+            EncodeError::TooLarge{path, packets, bits} => {
+                write!(f, "The {} sub-packets in {} bits of {} cannot be encoded", packets, bits, describe_path(path))
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+// Bits appended most significant first, as BitData reads them
+#[derive(Debug)] #[derive(Default)]
+struct BitWriter {
+    bits: Vec<bool>,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, bits: usize) {
+        for bit in (0 .. bits).rev() {
+            self.bits.push((value >> bit) & 1 == 1);
+        }
+    }
+
+    fn append(&mut self, other: &BitWriter) {
+        self.bits.extend_from_slice(&other.bits);
+    }
+
+    // Upper case hex digits, padded with zeros to whole bytes
+    fn to_hex(&self) -> String {
+        let mut res = String::new();
+        for chunk in self.bits.chunks(8) {
+            let byte = (0 .. 8).fold(0, |byte, i| (byte << 1) | chunk.get(i).copied().unwrap_or(false) as u8);
+            res.push_str(&format!("{:02X}", byte));
+        }
+        return res;
+    }
+}
+
+impl Packet {
+    //
+    // The transmission of the packet as hex. Operators keep their length
+    // type as long as their sub-packets fit its field and switch to the other
+    // one otherwise, a packet with more than 2047 sub-packets that take more
+    // than 32767 bits cannot be encoded at all. Neither can versions over 7
+    // or type ids that do not match the packet.
+    //
+    pub fn to_hex(&self) -> Result<String, EncodeError> {
+        let mut writer = BitWriter::default();
+        encode(self, &mut writer, &mut Vec::new())?;
+        return Ok(writer.to_hex());
+    }
+}
+
+fn encode(packet: &Packet, writer: &mut BitWriter, path: &mut Vec<usize>) -> Result<(), EncodeError> {
+    if packet.version > 7 {
+        return Err(EncodeError::Version{path: path.clone(), version: packet.version});
+    }
+    let type_id = match &packet.data {
+        PacketData::Literal(_) => 4,
+        PacketData::Operator(op_type, _, _) => op_type.type_id(),
+    };
+    if packet.type_id != type_id {
+        return Err(EncodeError::TypeId{path: path.clone(), type_id: packet.type_id});
+    }
+    writer.write(packet.version as u64, 3);
+    writer.write(packet.type_id as u64, 3);
+
+    let (length_type, packets) = match &packet.data {
+        PacketData::Literal(value) => {
+            encode_literal(value, writer);
+            return Ok(());
+        }
+        PacketData::Operator(_, length_type, packets) => (*length_type, packets),
+    };
+    let mut content = BitWriter::default();
+    for (i, packet) in packets.iter().enumerate() {
+        path.push(i);
+        encode(packet, &mut content, path)?;
+        path.pop();
+    }
+
+    let fits_bits = content.bits.len() < 1 << LENGTH_MODE_0_SIZE;
+    let fits_count = packets.len() < 1 << LENGTH_MODE_1_SIZE;
+    if !fits_bits && !fits_count {
+        return Err(EncodeError::TooLarge{path: path.clone(), packets: packets.len(), bits: content.bits.len()});
+    }
+    if (length_type == LengthType::Bits && fits_bits) || !fits_count {
+        writer.write(0, 1);
+        writer.write(content.bits.len() as u64, LENGTH_MODE_0_SIZE);
+    } else {
+        writer.write(1, 1);
+        writer.write(packets.len() as u64, LENGTH_MODE_1_SIZE);
+    }
+    writer.append(&content);
+    return Ok(());
+}
+
+// Groups of 4 bits, each but the last prefixed by a 1
+fn encode_literal(value: &BigUint, writer: &mut BitWriter) {
+    let groups = value.bits().div_ceil(4).max(1);
+    for group in (0 .. groups).rev() {
+        writer.write((group > 0) as u64, 1);
+        writer.write(value.nibble(group) as u64, 4);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day16::OpType;
+
+    #[test]
+    fn rejects_what_does_not_fit() {
+        let mut packet: Packet = "sum(1, max(2, 3))".parse().unwrap();
+        if let PacketData::Operator(_, _, packets) = &mut packet.data {
+            packets[1].version = 8;
+        }
+        assert_eq!(packet.to_hex(), Err(EncodeError::Version{path: vec![1], version: 8}));
+        assert_eq!(packet.to_hex().unwrap_err().to_string(), "Version 8 of sub-packet 1 does not fit 3 bits");
+
+        let mut packet = Packet::literal(1, BigUint::from(5));
+        packet.type_id = 9;
+        assert_eq!(packet.to_hex(), Err(EncodeError::TypeId{path: vec![], type_id: 9}));
+        packet.type_id = 0;
+        assert_eq!(packet.to_hex(), Err(EncodeError::TypeId{path: vec![], type_id: 0}));
+
+        // 2048 sub-packets of 26 bits each fit neither length field
+        let literals = vec![Packet::literal(0, BigUint::from(0xFFFF)); 2048];
+        let packet = Packet::operator(0, OpType::Sum, LengthType::Count, vec![Packet::operator(0, OpType::Maximum, LengthType::Bits, literals)]);
+        assert_eq!(packet.to_hex(), Err(EncodeError::TooLarge{path: vec![0], packets: 2048, bits: 2048 * 26}));
+        // One less fits the count
+        let literals = vec![Packet::literal(0, BigUint::from(0xFFFF)); 2047];
+        assert!(Packet::operator(0, OpType::Sum, LengthType::Bits, literals).to_hex().is_ok());
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+use crate::bignum::BigUint;
+
+use super::{describe_path, OpType, Packet, PacketData};
+
+//
+// A number type packets can be evaluated in. The operations return None when
+// the result does not fit the type.
+//
+pub trait Value: Ord + Sized {
+    fn from_literal(value: &BigUint) -> Option<Self>;
+    fn from_bool(value: bool) -> Self;
+    fn plus(&self, other: &Self) -> Option<Self>;
+    fn times(&self, other: &Self) -> Option<Self>;
+}
+
+// Overflow checked, anything beyond 64 bits is an error
+impl Value for u64 {
+    fn from_literal(value: &BigUint) -> Option<u64> {
+        return value.to_u64();
+    }
+
+    fn from_bool(value: bool) -> u64 {
+        return value as u64;
+    }
+
+    fn plus(&self, other: &u64) -> Option<u64> {
+        return self.checked_add(*other);
+    }
+
+    fn times(&self, other: &u64) -> Option<u64> {
+        return self.checked_mul(*other);
+    }
+}
+
+// Exact, never overflows
+impl Value for BigUint {
+    fn from_literal(value: &BigUint) -> Option<BigUint> {
+        return Some(value.clone());
+    }
+
+    fn from_bool(value: bool) -> BigUint {
+        return BigUint::from(value as u64);
+    }
+
+    fn plus(&self, other: &BigUint) -> Option<BigUint> {
+        return Some(self + other);
+    }
+
+    fn times(&self, other: &BigUint) -> Option<BigUint> {
+        return Some(self * other);
+    }
+}
+
+//
+// Why a packet has no value. Packets are located by their path, the index of
+// each sub-packet on the way down from the outermost packet.
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum EvalError {
+    // The value of the packet does not fit the number type
+    Overflow{path: Vec<usize>},
+    // Only possible for packets built by hand, decoding rejects them
+    OperandCount{path: Vec<usize>, op_type: OpType, count: usize},
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Overflow{path} => write!(f, "The value of {} overflows", describe_path(path)),
+            EvalError::OperandCount{path, op_type, count} => {
+                write!(f, "Operator {} of {} has {} sub-packets", op_type.name(), describe_path(path), count)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+impl Packet {
+    //
+    // The value of the expression in the given number type, e.g.
+    // evaluate_as::<BigUint>() for the exact value
+    //
+    pub fn evaluate_as<V: Value>(&self) -> Result<V, EvalError> {
+        return evaluate_at(self, &mut Vec::new());
+    }
+
+    // In 64 bits, an error if any packet overflows
+    pub fn evaluate(&self) -> Result<u64, EvalError> {
+        return self.evaluate_as::<u64>();
+    }
+
+    pub fn evaluate_exact(&self) -> Result<BigUint, EvalError> {
+        return self.evaluate_as::<BigUint>();
+    }
+}
+
+fn evaluate_at<V: Value>(packet: &Packet, path: &mut Vec<usize>) -> Result<V, EvalError> {
+    let (op_type, packets) = match &packet.data {
+        PacketData::Literal(value) => return V::from_literal(value).ok_or_else(|| EvalError::Overflow{path: path.clone()}),
+        PacketData::Operator(op_type, _, packets) => (*op_type, packets),
+    };
+    if packets.is_empty() || (op_type.is_comparison() && packets.len() != 2) {
+        return Err(EvalError::OperandCount{path: path.clone(), op_type: op_type, count: packets.len()});
+    }
+
+    let mut values: Vec<V> = Vec::with_capacity(packets.len());
+    for (i, packet) in packets.iter().enumerate() {
+        path.push(i);
+        values.push(evaluate_at(packet, path)?);
+        path.pop();
+    }
+    let mut values = values.into_iter();
+    let first = values.next().unwrap();
+    let result = match op_type {
+        OpType::Sum => values.try_fold(first, |sum, value| sum.plus(&value)),
+        OpType::Product => values.try_fold(first, |product, value| product.times(&value)),
+        OpType::Minimum => Some(values.fold(first, |min, value| min.min(value))),
+        OpType::Maximum => Some(values.fold(first, |max, value| max.max(value))),
+        _ => {
+            let second = values.next().unwrap();
+            Some(V::from_bool(match op_type {
+                OpType::GreaterThan => first > second,
+                OpType::LessThan => first < second,
+                _ => first == second,
+            }))
+        }
+    };
+    return result.ok_or_else(|| EvalError::Overflow{path: path.clone()});
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day16::LengthType;
+
+    #[test]
+    fn detects_overflow() {
+        let packet: Packet = "sum(1, product(4294967296, 4294967296), 7)".parse().unwrap();
+        assert_eq!(packet.evaluate(), Err(EvalError::Overflow{path: vec![1]}));
+        assert_eq!(packet.evaluate().unwrap_err().to_string(), "The value of sub-packet 1 overflows");
+        assert_eq!(packet.evaluate_exact().unwrap().to_string(), "18446744073709551624");
+
+        let packet: Packet = "lt(max(18446744073709551615, 3), sum(18446744073709551615, 1))".parse().unwrap();
+        assert_eq!(packet.evaluate(), Err(EvalError::Overflow{path: vec![1]}));
+        assert_eq!(packet.evaluate_exact(), Ok(BigUint::from(1)));
+
+        let packet: Packet = "min(99999999999999999999, 5)".parse().unwrap();
+        assert_eq!(packet.evaluate(), Err(EvalError::Overflow{path: vec![0]}));
+        assert_eq!(packet.evaluate_as::<BigUint>(), Ok(BigUint::from(5)));
+    }
+
+    #[test]
+    fn rejects_hand_built_operators() {
+        let packet = Packet::operator(0, OpType::Sum, LengthType::Count,
+                                      vec![Packet::operator(0, OpType::EqualTo, LengthType::Count, vec![Packet::literal(0, BigUint::from(1))])]);
+        assert_eq!(packet.evaluate(), Err(EvalError::OperandCount{path: vec![0], op_type: OpType::EqualTo, count: 1}));
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{self, ParseError};
+
+use super::{LengthType, Packet, PacketData, OP_TYPES};
+
+// Operators as calls on their sub-packets, e.g. "sum(3, max(7, 8))"
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (op_type, packets) = match &self.data {
+            PacketData::Literal(value) => return write!(f, "{}", value),
+            PacketData::Operator(op_type, _, packets) => (op_type, packets),
+        };
+        write!(f, "{}(", op_type.name())?;
+        for (i, packet) in packets.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", packet)?;
+        }
+        write!(f, ")")
+    }
+}
+
+//
+// Reads an expression as printed back into packets. Every packet gets
+// version 0 and operators count their sub-packets.
+//
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Packet, ParseError> {
+        let mut parser = Parser{data: s, index: 0};
+        let packet = parser.packet()?;
+        if parser.skip_whitespace() < s.len() {
+            return Err(parser.error("Expected the end of the expression"));
+        }
+        return Ok(packet);
+    }
+}
+
+struct Parser<'a> {
+    data: &'a str,
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) -> usize {
+        let rest = &self.data[self.index ..];
+        self.index += rest.len() - rest.trim_start().len();
+        return self.index;
+    }
+
+    // The run of alphanumeric characters at the index, or the single character there
+    fn token(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let rest = &self.data[self.index ..];
+        let len = rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len());
+        return if len > 0 { &rest[.. len] } else { error::char_at(self.data, self.index) };
+    }
+
+    fn error(&mut self, reason: &str) -> ParseError {
+        let token = self.token();
+        return ParseError::at(16, self.data, token, reason);
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.token() != c.to_string() {
+            return Err(self.error(&format!("Expected \"{}\"", c)));
+        }
+        self.index += 1;
+        return Ok(());
+    }
+
+    fn packet(&mut self) -> Result<Packet, ParseError> {
+        let token = self.token();
+        if token.starts_with(|c: char| c.is_ascii_digit()) {
+            let value = error::parse_number(16, self.data, token)?;
+            self.index += token.len();
+            return Ok(Packet::literal(0, value));
+        }
+
+        let op_type = match OP_TYPES.iter().find(|op| op.name() == token) {
+            Some(op_type) => *op_type,
+            None => return Err(self.error("Expected a number or an operator")),
+        };
+        self.index += token.len();
+        self.expect('(')?;
+        let mut packets = vec![self.packet()?];
+        // Comparisons are reported at the first operand too many or the end of too few
+        let arity_error = format!("Expected 2 operands for {}", op_type.name());
+        while self.token() == "," {
+            if op_type.is_comparison() && packets.len() == 2 {
+                return Err(self.error(&arity_error));
+            }
+            self.index += 1;
+            packets.push(self.packet()?);
+        }
+        if op_type.is_comparison() && packets.len() != 2 {
+            return Err(self.error(&arity_error));
+        }
+        self.expect(')')?;
+        return Ok(Packet::operator(0, op_type, LengthType::Count, packets));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_malformed_expressions() {
+        let error = "sum(1, avg(2, 3))".parse::<Packet>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (8, "avg"));
+        let error = "gt(1, 2, 3)".parse::<Packet>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (8, ","));
+        let error = "max(1 2)".parse::<Packet>().unwrap_err();
+        assert_eq!(error.reason, "Expected \")\"");
+        assert!("min(4)\n".parse::<Packet>().is_ok());
+        assert!("min(4) 5".parse::<Packet>().is_err());
+    }
+}
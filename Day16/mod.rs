@@ -0,0 +1,401 @@
+use std::fmt;
+
+use crate::bignum::BigUint;
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub mod expression;
+pub mod encode;
+pub mod stream;
+pub mod evaluate;
+
+pub struct Day16;
+
+//
+// Why a transmission cannot be decoded, located by the offset of the bit at
+// which decoding failed. Characters that are not hex digits are located by
+// the bit they would have started.
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct DecodeError {
+    pub bit: usize,
+    pub kind: DecodeErrorKind,
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum DecodeErrorKind {
+    InvalidDigit(char),
+    // A field needs more bits than are left
+    UnexpectedEnd{needed: usize},
+    // The sub-packets of an operator with length type 0 run past its length
+    LengthOverrun{end: usize},
+    OperandCount{op_type: OpType, count: usize},
+    // Operators nested deeper than MAX_DEPTH
+    TooDeep,
+    // Bits other than zero padding after the outermost packet
+    TrailingData,
+}
+
+impl fmt::Display for DecodeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeErrorKind::InvalidDigit(c) => write!(f, "Expected a hex digit, found {:?}", c),
+            DecodeErrorKind::UnexpectedEnd{needed} => write!(f, "Unexpected end of transmission, {} more bits needed", needed),
+            DecodeErrorKind::LengthOverrun{end} => write!(f, "Sub-packets overrun their length ending at bit {}", end),
+            DecodeErrorKind::OperandCount{op_type, count} => write!(f, "Operator {} with {} sub-packets", op_type.name(), count),
+            DecodeErrorKind::TooDeep => write!(f, "Packets nested deeper than {}", MAX_DEPTH),
+            DecodeErrorKind::TrailingData => write!(f, "Data after the packet"),
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bit {}: {}", self.bit, self.kind)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl DecodeError {
+    fn new(bit: usize, kind: DecodeErrorKind) -> Self {
+        return DecodeError{bit: bit, kind: kind};
+    }
+
+    //
+    // The error located within the hex the bits were decoded from, at the
+    // digit holding the bit
+    //
+    pub fn to_parse_error(&self, hex: &str) -> ParseError {
+        let digit = hex.char_indices().filter(|(_, c)| !c.is_whitespace()).nth(self.bit / 4);
+        let token = match digit {
+            Some((i, _)) => error::char_at(hex, i),
+            None => &hex[hex.len() ..],
+        };
+        return ParseError::at(16, hex, token, &self.kind.to_string());
+    }
+}
+
+// Nesting beyond this is rejected rather than risking the stack
+pub const MAX_DEPTH: usize = 512;
+
+#[derive(Debug)]
+pub struct BitData {
+    data : Vec<u8>,
+    // Number of bits, the last byte may only be half used
+    len: usize,
+}
+
+impl BitData {
+    // None for bits past the end or more than 64 of them
+    pub fn get(&self, bit_index: usize, bits: usize) -> Option<u64> {
+        if bits > 64 || bit_index.checked_add(bits)? > self.len {
+            return None;
+        }
+        let mut res = 0;
+
+        for bit in bit_index .. (bit_index + bits) {
+            let byte_index = bit / 8;
+            let subindex =  7 - (bit % 8);
+
+            let bit_value = (self.data[byte_index] >> subindex) & 1;
+            res |= (bit_value as u64) << ((bits - (bit - bit_index)) - 1);
+        }
+
+        return Some(res);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    // Reads the next bits and advances the index past them
+    fn read(&self, index: &mut usize, bits: usize) -> Result<u64, DecodeError> {
+        let res = self.get(*index, bits).ok_or_else(|| {
+            DecodeError::new(*index, DecodeErrorKind::UnexpectedEnd{needed: *index + bits - self.len})
+        })?;
+        *index += bits;
+        return Ok(res);
+    }
+}
+
+// Hex digits in either case, whitespace in between is skipped
+pub fn parse_hex(s: &str) -> Result<BitData, DecodeError> {
+    let mut data = Vec::new();
+    let mut len = 0;
+    for c in s.chars().filter(|c| !c.is_whitespace()) {
+        let nibble = c.to_digit(16).ok_or(DecodeError::new(len, DecodeErrorKind::InvalidDigit(c)))? as u8;
+        if len % 8 == 0 {
+            data.push(nibble << 4);
+        } else {
+            *data.last_mut().unwrap() |= nibble;
+        }
+        len += 4;
+    }
+    return Ok(BitData{data: data, len: len});
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum OpType {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+pub const OP_TYPES: [OpType; 7] = [OpType::Sum, OpType::Product, OpType::Minimum, OpType::Maximum,
+                                   OpType::GreaterThan, OpType::LessThan, OpType::EqualTo];
+
+impl OpType {
+    pub fn type_id(&self) -> u8 {
+        return match self {
+            OpType::Sum => 0,
+            OpType::Product => 1,
+            OpType::Minimum => 2,
+            OpType::Maximum => 3,
+            OpType::GreaterThan => 5,
+            OpType::LessThan => 6,
+            OpType::EqualTo => 7,
+        };
+    }
+
+    // None for the literal type 4 and ids outside of 3 bits
+    pub fn from_type_id(type_id: u8) -> Option<OpType> {
+        return OP_TYPES.iter().copied().find(|op| op.type_id() == type_id);
+    }
+
+    // As written in expressions
+    pub fn name(&self) -> &'static str {
+        return match self {
+            OpType::Sum => "sum",
+            OpType::Product => "product",
+            OpType::Minimum => "min",
+            OpType::Maximum => "max",
+            OpType::GreaterThan => "gt",
+            OpType::LessThan => "lt",
+            OpType::EqualTo => "eq",
+        };
+    }
+
+    pub fn is_comparison(&self) -> bool {
+        return matches!(self, OpType::GreaterThan | OpType::LessThan | OpType::EqualTo);
+    }
+}
+
+// How an operator packet gives the extent of its sub-packets
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum LengthType {
+    // Length type 0, the total number of bits
+    Bits,
+    // Length type 1, the number of sub-packets
+    Count,
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum PacketData {
+    Literal(BigUint),
+    Operator(OpType, LengthType, Vec<Packet>),
+}
+
+// Packets within a tree are located by the index of each sub-packet on the way down
+fn describe_path(path: &[usize]) -> String {
+    if path.is_empty() {
+        return "the outermost packet".to_string();
+    }
+    let indices: Vec<String> = path.iter().map(|i| i.to_string()).collect();
+    return format!("sub-packet {}", indices.join("."));
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct Packet {
+    pub version: u8,
+    pub type_id: u8,
+    pub data: PacketData,
+}
+
+impl Packet {
+    pub fn literal(version: u8, value: BigUint) -> Self {
+        return Packet{version: version, type_id: 4, data: PacketData::Literal(value)};
+    }
+
+    pub fn operator(version: u8, op_type: OpType, length_type: LengthType, packets: Vec<Packet>) -> Self {
+        return Packet{version: version, type_id: op_type.type_id(), data: PacketData::Operator(op_type, length_type, packets)};
+    }
+
+    // The direct sub-packets, none for literals
+    pub fn packets(&self) -> &[Packet] {
+        return match &self.data {
+            PacketData::Literal(_) => &[],
+            PacketData::Operator(_, _, packets) => packets,
+        };
+    }
+
+    pub fn version_sum(&self) -> u64 {
+        return self.version as u64 + self.packets().iter().map(|p| p.version_sum()).sum::<u64>();
+    }
+}
+
+fn parse_literal(data: &BitData, index: &mut usize) -> Result<PacketData, DecodeError> {
+    let mut last_group = false;
+
+    let mut res = BigUint::zero();
+    while !last_group {
+        last_group = data.read(index, 1)? == 0;
+        res.mul_add(16, data.read(index, 4)? as u32);
+    }
+    return Ok(PacketData::Literal(res));
+}
+
+const LENGTH_MODE_0_SIZE: usize = 15;
+const LENGTH_MODE_1_SIZE: usize = 11;
+
+fn parse_operator(op_type: OpType, data: &BitData, index: &mut usize, depth: usize) -> Result<PacketData, DecodeError> {
+    let start = *index;
+    let length_type_id = data.read(index, 1)?;
+
+    let mut subpackets = Vec::new();
+    let length_type = if length_type_id == 0 {
+        let terminate_index = data.read(index, LENGTH_MODE_0_SIZE)? as usize + *index;
+        while *index < terminate_index {
+            let packet_start = *index;
+            subpackets.push(parse_packet_at(data, index, depth + 1)?);
+            if *index > terminate_index {
+                return Err(DecodeError::new(packet_start, DecodeErrorKind::LengthOverrun{end: terminate_index}));
+            }
+        }
+        LengthType::Bits
+    } else {
+        let package_count = data.read(index, LENGTH_MODE_1_SIZE)? as usize;
+        for _package in 0 .. package_count {
+            subpackets.push(parse_packet_at(data, index, depth + 1)?);
+        }
+        LengthType::Count
+    };
+
+    if subpackets.is_empty() || (op_type.is_comparison() && subpackets.len() != 2) {
+        return Err(DecodeError::new(start, DecodeErrorKind::OperandCount{op_type: op_type, count: subpackets.len()}));
+    }
+
+    return Ok(PacketData::Operator(op_type, length_type, subpackets));
+}
+
+fn parse_packet_at(data: &BitData, index: &mut usize, depth: usize) -> Result<Packet, DecodeError> {
+    if depth > MAX_DEPTH {
+        return Err(DecodeError::new(*index, DecodeErrorKind::TooDeep));
+    }
+    let version = data.read(index, 3)? as u8;
+    let type_id = data.read(index, 3)? as u8;
+
+    let data = match OpType::from_type_id(type_id) {
+        None => parse_literal(data, index)?,
+        Some(op_type) => parse_operator(op_type, data, index, depth)?,
+    };
+    return Ok(Packet{version: version, type_id: type_id, data: data});
+}
+
+// Decodes the packet starting at the index and advances the index past it
+pub fn parse_packet(data: &BitData, index: &mut usize) -> Result<Packet, DecodeError> {
+    return parse_packet_at(data, index, 0);
+}
+
+//
+// Decodes a whole transmission: a single packet, followed by nothing but
+// zeros
+//
+pub fn decode(hex: &str) -> Result<Packet, DecodeError> {
+    let data = parse_hex(hex)?;
+    let mut index = 0;
+    let packet = parse_packet(&data, &mut index)?;
+    while index < data.len() {
+        if data.read(&mut index, 1)? != 0 {
+            return Err(DecodeError::new(index - 1, DecodeErrorKind::TrailingData));
+        }
+    }
+    return Ok(packet);
+}
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn parse(data: &str) -> Result<Packet, ParseError> {
+        return decode(data).map_err(|e| e.to_parse_error(data));
+    }
+
+    fn part1(packet: &Packet) -> Result<Answer, NoAnswer> {
+        return Ok(packet.version_sum().into());
+    }
+
+    fn part2(packet: &Packet) -> Result<Answer, NoAnswer> {
+        let value = packet.evaluate_exact().expect("Decoded packets have valid operands");
+        return Ok(match value.to_u64() {
+            Some(value) => value.into(),
+            None => value.to_string().into(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_the_packet_tree() {
+        let packet = Day16::parse("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(packet.to_string(), "eq(sum(1, 3), product(2, 2))");
+        assert_eq!(packet.evaluate(), Ok(1));
+        assert_eq!(packet.packets()[1].packets().len(), 2);
+    }
+
+    #[test]
+    fn encodes_expressions() {
+        for hex in ["D2FE28", "38006F45291200", "EE00D40C823060", "8A004A801A8002F478", "9C0141080250320F1802104A08"] {
+            let packet = Day16::parse(hex).unwrap();
+            // The transmissions are padded with zeros to whole bytes
+            let encoded = packet.to_hex().unwrap();
+            assert!(hex.starts_with(&encoded), "{} encoded as {}", hex, encoded);
+        }
+
+        let packet: Packet = "sum(3, max(7, 8))".parse().unwrap();
+        assert_eq!(packet.to_string(), "sum(3, max(7, 8))");
+        let decoded = Day16::parse(&packet.to_hex().unwrap()).unwrap();
+        assert_eq!((decoded.evaluate(), decoded), (Ok(11), packet));
+    }
+
+    #[test]
+    fn decodes_loose_hex() {
+        assert_eq!(decode("d2fe28").unwrap(), decode("D2 FE\n28").unwrap());
+        assert_eq!(decode("d2fe28").unwrap().to_string(), "2021");
+
+        let error = Day16::parse("D2F\nG28").unwrap_err();
+        assert_eq!((error.line, error.column, error.token.as_str()), (2, 1, "G"));
+    }
+
+    #[test]
+    fn reports_bit_offsets() {
+        let error = |hex: &str| decode(hex).unwrap_err();
+        assert_eq!(error("D2FG28"), DecodeError::new(12, DecodeErrorKind::InvalidDigit('G')));
+        assert_eq!(error("D2FE"), DecodeError::new(16, DecodeErrorKind::UnexpectedEnd{needed: 1}));
+        // A sum of 10 bits holding a literal of 11
+        assert_eq!(error("000028408"), DecodeError::new(22, DecodeErrorKind::LengthOverrun{end: 32}));
+        assert_eq!(error("D2FE2801"), DecodeError::new(31, DecodeErrorKind::TrailingData));
+        assert_eq!(error("D2FE2801").to_parse_error("D2FE2801").column, 8);
+
+        let nested = format!("{}1{}", "sum(".repeat(MAX_DEPTH + 1), ")".repeat(MAX_DEPTH + 1));
+        let hex = nested.parse::<Packet>().unwrap().to_hex().unwrap();
+        assert_eq!(error(&hex).kind, DecodeErrorKind::TooDeep);
+    }
+
+    #[test]
+    fn decodes_large_literals() {
+        let value: BigUint = "123456789012345678901234567890".parse().unwrap();
+        let packet = decode(&Packet::literal(5, value.clone()).to_hex().unwrap()).unwrap();
+        assert_eq!((packet.version, packet.data), (5, PacketData::Literal(value)));
+    }
+}
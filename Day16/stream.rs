@@ -0,0 +1,370 @@
+use std::fmt;
+use std::io::{self, Read};
+
+use crate::bignum::BigUint;
+
+use super::{DecodeError, DecodeErrorKind, LengthType, OpType, Packet, MAX_DEPTH};
+use super::{LENGTH_MODE_0_SIZE, LENGTH_MODE_1_SIZE};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Decode(DecodeError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "Could not read the transmission: {}", e),
+            StreamError::Decode(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        return StreamError::Io(e);
+    }
+}
+
+impl From<DecodeError> for StreamError {
+    fn from(e: DecodeError) -> Self {
+        return StreamError::Decode(e);
+    }
+}
+
+fn decode_error(bit: usize, kind: DecodeErrorKind) -> StreamError {
+    return StreamError::Decode(DecodeError{bit: bit, kind: kind});
+}
+
+//
+// Reads the bits of a hex transmission as they arrive from the reader, a
+// buffer's worth of characters at a time. Whitespace is skipped.
+//
+pub struct BitReader<R: Read> {
+    reader: R,
+    buffer: [u8; 4096],
+    buffered: usize,
+    consumed: usize,
+    // Bits read from the reader but not yet handed out, the lowest acc_bits
+    acc: u64,
+    acc_bits: usize,
+    // Number of bits handed out
+    position: usize,
+}
+
+impl<R: Read> BitReader<R> {
+    pub fn new(reader: R) -> Self {
+        return BitReader{reader: reader, buffer: [0; 4096], buffered: 0, consumed: 0, acc: 0, acc_bits: 0, position: 0};
+    }
+
+    // Offset of the next bit
+    pub fn position(&self) -> usize {
+        return self.position;
+    }
+
+    fn next_char(&mut self) -> Result<Option<u8>, StreamError> {
+        while self.consumed == self.buffered {
+            self.buffered = match self.reader.read(&mut self.buffer) {
+                Ok(0) => return Ok(None),
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            self.consumed = 0;
+        }
+        self.consumed += 1;
+        return Ok(Some(self.buffer[self.consumed - 1]));
+    }
+
+    // Buffers up to the given number of bits, returns how many are available
+    fn fill(&mut self, bits: usize) -> Result<usize, StreamError> {
+        while self.acc_bits < bits {
+            let c = match self.next_char()? {
+                Some(c) if c.is_ascii_whitespace() => continue,
+                Some(c) => c as char,
+                None => break,
+            };
+            let bit = self.position + self.acc_bits;
+            let nibble = c.to_digit(16).ok_or_else(|| decode_error(bit, DecodeErrorKind::InvalidDigit(c)))?;
+            self.acc = (self.acc << 4) | nibble as u64;
+            self.acc_bits += 4;
+        }
+        return Ok(self.acc_bits.min(bits));
+    }
+
+    // The next bits, at most 32 of them as the buffered bits have to fit in a u64
+    pub fn read(&mut self, bits: usize) -> Result<u64, StreamError> {
+        assert!(bits <= 32, "Cannot read {} bits at once, at most 32", bits);
+        let available = self.fill(bits)?;
+        if available < bits {
+            return Err(decode_error(self.position, DecodeErrorKind::UnexpectedEnd{needed: bits - available}));
+        }
+        self.acc_bits -= bits;
+        self.position += bits;
+        let res = self.acc >> self.acc_bits;
+        self.acc &= (1 << self.acc_bits) - 1;
+        return Ok(res);
+    }
+
+    pub fn at_end(&mut self) -> Result<bool, StreamError> {
+        return Ok(self.fill(1)? == 0);
+    }
+}
+
+// What the decoder came across, in the order of the transmission
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Event {
+    // The header of a packet starting at the bit, for operators including their length field
+    PacketStart{bit: usize, version: u8, type_id: u8, length_type: Option<LengthType>},
+    // The value of the literal packet started last
+    Literal(BigUint),
+    // All sub-packets of the innermost open operator were read
+    OperatorEnd(OpType),
+}
+
+// An operator whose sub-packets are still being read
+struct Open {
+    op_type: OpType,
+    // Just past the header, as decode reports operand errors
+    start: usize,
+    length: Remaining,
+    count: usize,
+    child_start: usize,
+}
+
+enum Remaining {
+    // The bit at which the sub-packets end
+    Bits(usize),
+    Count(usize),
+}
+
+//
+// Decodes a transmission as it is read. Only the operators enclosing the
+// current packet are kept, at most MAX_DEPTH of them, so memory does not
+// grow with the length of the transmission, only with the size of the
+// literals. Errors are those of decode at the same bit offsets, after an
+// error the iterator ends.
+//
+pub struct Decoder<R: Read> {
+    bits: BitReader<R>,
+    open: Vec<Open>,
+    in_literal: bool,
+    started: bool,
+    done: bool,
+}
+
+impl<R: Read> Decoder<R> {
+    pub fn new(reader: R) -> Self {
+        return Decoder{bits: BitReader::new(reader), open: Vec::new(), in_literal: false, started: false, done: false};
+    }
+
+    fn next_event(&mut self) -> Result<Option<Event>, StreamError> {
+        if self.in_literal {
+            self.in_literal = false;
+            let mut value = BigUint::zero();
+            let mut last_group = false;
+            while !last_group {
+                last_group = self.bits.read(1)? == 0;
+                value.mul_add(16, self.bits.read(4)? as u32);
+            }
+            return Ok(Some(Event::Literal(value)));
+        }
+
+        let position = self.bits.position();
+        if let Some(open) = self.open.last() {
+            let complete = match open.length {
+                Remaining::Bits(end) if position > end => {
+                    return Err(decode_error(open.child_start, DecodeErrorKind::LengthOverrun{end: end}));
+                }
+                Remaining::Bits(end) => position == end,
+                Remaining::Count(left) => left == 0,
+            };
+            if complete {
+                let open = self.open.pop().unwrap();
+                if open.count == 0 || (open.op_type.is_comparison() && open.count != 2) {
+                    return Err(decode_error(open.start, DecodeErrorKind::OperandCount{op_type: open.op_type, count: open.count}));
+                }
+                return Ok(Some(Event::OperatorEnd(open.op_type)));
+            }
+        } else if self.started {
+            // Only zero padding may follow the outermost packet
+            while !self.bits.at_end()? {
+                if self.bits.read(1)? != 0 {
+                    return Err(decode_error(self.bits.position() - 1, DecodeErrorKind::TrailingData));
+                }
+            }
+            return Ok(None);
+        }
+
+        if self.open.len() > MAX_DEPTH {
+            return Err(decode_error(position, DecodeErrorKind::TooDeep));
+        }
+        self.started = true;
+        if let Some(parent) = self.open.last_mut() {
+            parent.count += 1;
+            parent.child_start = position;
+            if let Remaining::Count(left) = &mut parent.length {
+                *left -= 1;
+            }
+        }
+
+        let version = self.bits.read(3)? as u8;
+        let type_id = self.bits.read(3)? as u8;
+        let length_type = match OpType::from_type_id(type_id) {
+            None => {
+                self.in_literal = true;
+                None
+            }
+            Some(op_type) => {
+                let start = self.bits.position();
+                let (length_type, length) = if self.bits.read(1)? == 0 {
+                    let length = self.bits.read(LENGTH_MODE_0_SIZE)? as usize;
+                    (LengthType::Bits, Remaining::Bits(self.bits.position() + length))
+                } else {
+                    (LengthType::Count, Remaining::Count(self.bits.read(LENGTH_MODE_1_SIZE)? as usize))
+                };
+                self.open.push(Open{op_type: op_type, start: start, length: length, count: 0, child_start: start});
+                Some(length_type)
+            }
+        };
+        return Ok(Some(Event::PacketStart{bit: position, version: version, type_id: type_id, length_type: length_type}));
+    }
+}
+
+impl<R: Read> Iterator for Decoder<R> {
+    type Item = Result<Event, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let event = self.next_event();
+        if !matches!(event, Ok(Some(_))) {
+            self.done = true;
+        }
+        return event.transpose();
+    }
+}
+
+// Sums the versions of all packets without keeping any of them
+pub fn version_sum<R: Read>(reader: R) -> Result<u64, StreamError> {
+    let mut sum = 0;
+    for event in Decoder::new(reader) {
+        if let Event::PacketStart{version, ..} = event? {
+            sum += version as u64;
+        }
+    }
+    return Ok(sum);
+}
+
+//
+// Builds the packet tree from the events, the same packet decode gives for
+// the whole transmission
+//
+pub fn read_packet<R: Read>(reader: R) -> Result<Packet, StreamError> {
+    // The operators being built with their sub-packets so far
+    let mut open: Vec<(u8, LengthType, Vec<Packet>)> = Vec::new();
+    let mut literal_version = 0;
+    let mut packet = None;
+
+    for event in Decoder::new(reader) {
+        let complete = match event? {
+            Event::PacketStart{version, length_type: Some(length_type), ..} => {
+                open.push((version, length_type, Vec::new()));
+                continue;
+            }
+            Event::PacketStart{version, length_type: None, ..} => {
+                literal_version = version;
+                continue;
+            }
+            Event::Literal(value) => Packet::literal(literal_version, value),
+            Event::OperatorEnd(op_type) => {
+                let (version, length_type, packets) = open.pop().unwrap();
+                Packet::operator(version, op_type, length_type, packets)
+            }
+        };
+        match open.last_mut() {
+            Some((_, _, packets)) => packets.push(complete),
+            None => packet = Some(complete),
+        }
+    }
+    return Ok(packet.unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day16::decode;
+
+    // Hands out a single byte per read, then fails if asked to
+    struct Trickle<'a> {
+        data: &'a [u8],
+        fail_at_end: bool,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.data.is_empty() {
+                return if self.fail_at_end { Err(io::Error::other("Connection lost")) } else { Ok(0) };
+            }
+            buf[0] = self.data[0];
+            self.data = &self.data[1 ..];
+            return Ok(1);
+        }
+    }
+
+    fn trickle(hex: &str) -> Trickle<'_> {
+        return Trickle{data: hex.as_bytes(), fail_at_end: false};
+    }
+
+    #[test]
+    fn yields_events() {
+        let events: Vec<Event> = Decoder::new(trickle("38006F45291200")).map(|e| e.unwrap()).collect();
+        assert_eq!(events, vec![
+            Event::PacketStart{bit: 0, version: 1, type_id: 6, length_type: Some(LengthType::Bits)},
+            Event::PacketStart{bit: 22, version: 6, type_id: 4, length_type: None},
+            Event::Literal(BigUint::from(10)),
+            Event::PacketStart{bit: 33, version: 2, type_id: 4, length_type: None},
+            Event::Literal(BigUint::from(20)),
+            Event::OperatorEnd(OpType::LessThan),
+        ]);
+        assert_eq!(version_sum(trickle("8A004A801A8002F478")).unwrap(), 16);
+    }
+
+    #[test]
+    fn matches_decode() {
+        for hex in ["D2FE28", "ee00d40c823060", "C0015000016115A2E0802F182340", "9C01 4108 0250\n320F 1802 104A 08"] {
+            assert_eq!(read_packet(trickle(hex)).unwrap(), decode(hex).unwrap(), "{}", hex);
+        }
+        for hex in ["", "D2FG28", "D2FE", "000028408", "D2FE2801", "38006F45"] {
+            match read_packet(trickle(hex)) {
+                Err(StreamError::Decode(error)) => assert_eq!(error, decode(hex).unwrap_err(), "{}", hex),
+                other => panic!("{}: {:?}", hex, other),
+            }
+        }
+    }
+
+    #[test]
+    fn reads_up_to_32_bits() {
+        let mut bits = BitReader::new(trickle("F0123456789ABCDEF"));
+        assert_eq!(bits.read(4).unwrap(), 0xF);
+        assert_eq!(bits.read(32).unwrap(), 0x01234567);
+        assert_eq!(bits.read(32).unwrap(), 0x89ABCDEF);
+        assert!(bits.at_end().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot read 33 bits at once, at most 32")]
+    fn rejects_wider_reads() {
+        let _ = BitReader::new(trickle("123456789")).read(33);
+    }
+
+    #[test]
+    fn reports_read_errors() {
+        let error = read_packet(Trickle{data: b"38006F45", fail_at_end: true}).unwrap_err();
+        assert_eq!(error.to_string(), "Could not read the transmission: Connection lost");
+    }
+}
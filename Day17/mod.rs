@@ -0,0 +1,150 @@
+use std::cmp;
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day17;
+
+type Point = (i32, i32);
+type Velocity = (i32, i32);
+
+#[derive(Debug)]
+pub struct Target {
+    start: Point,
+    end: Point,
+}
+
+#[derive(Debug)]
+enum Outcome{
+    Overshoot,
+    Undershoot,
+    TooFastY,
+    Hit((Point, i32)),
+}
+
+fn compute_trajectory(v0: Velocity, target: &Target) -> Outcome {
+    let mut pos = (0, 0);
+    let mut vt = v0;
+    let mut peak = 0;
+
+    loop {
+        let prev_pos = pos;
+
+        pos.0 += vt.0;
+        pos.1 += vt.1;
+
+        peak = cmp::max(peak, pos.1);
+
+        vt.0 += match vt.0.cmp(&0) {
+            cmp::Ordering::Greater => -1,
+            cmp::Ordering::Less => 1,
+            cmp::Ordering::Equal => 0,
+        };
+        vt.1 -= 1;
+
+        if prev_pos.1 > target.start.1 && pos.1 < target.end.1 {
+            return Outcome::TooFastY;
+        } else if pos.1 < target.end.1 {
+            if pos.0 < target.start.0 {
+                return Outcome::Undershoot;
+            } else {
+                return Outcome::Overshoot;
+            }
+        } else if pos.0 < target.start.0 && vt.0 == 0 {
+            return Outcome::Undershoot;
+        }
+
+        if pos.0 >= target.start.0 && pos.0 <= target.end.0 &&
+           pos.1 <= target.start.1 && pos.1 >= target.end.1 {
+               return Outcome::Hit((pos, peak));
+           }
+    }
+}
+
+// Returns each hitting horizontal velocity with the peak height of its trajectory
+fn find_horizontal_solution(vertical_velocity: i32, target: &Target) -> Vec<(i32, i32)> {
+    let mut hits = Vec::new();
+    for cand_hv0 in 0 .. target.end.0 + 1 {
+        let res = compute_trajectory((cand_hv0, vertical_velocity), target);
+        if let Outcome::Hit((_pos, peak)) = res {
+            hits.push((cand_hv0, peak));
+        }
+    }
+    return hits;
+}
+
+// Maps every initial velocity that hits the target to the peak height reached
+fn find_solutions(target: &Target) -> HashMap<Velocity, i32> {
+    let lowest = cmp::min(target.start.1, target.end.1) - 1;
+    let highest =  cmp::min(target.start.1, target.end.1).abs();
+    let mut all_solutions = HashMap::new();
+
+    for vv0 in lowest ..= highest {
+        let solutions = find_horizontal_solution(vv0, target);
+        for (solution, peak) in solutions {
+            all_solutions.insert((solution, vv0), peak);
+        }
+    }
+    return all_solutions;
+}
+
+impl Solution for Day17 {
+    type Input = Target;
+
+    fn parse(data: &str) -> Result<Target, ParseError> {
+        let target_re = Regex::new(r"^target area: x=([-\d]+)\.\.([-\d]+), y=([-\d]+)\.\.([-\d]+)$").unwrap();
+
+        let cap = target_re.captures(data)
+                           .ok_or_else(|| ParseError::at(17, data, data, "Expected \"target area: x=<from>..<to>, y=<from>..<to>\""))?;
+        let token = |i: usize| cap.get(i).unwrap().as_str();
+        let coord = |i: usize| error::parse_number::<i32>(17, data, token(i));
+        let (x_from, x_to, y_from, y_to) = (coord(1)?, coord(2)?, coord(3)?, coord(4)?);
+        // The search shoots to the right and down
+        if x_from > x_to {
+            return Err(ParseError::at(17, data, token(1), "The x range is reversed"));
+        }
+        if y_from > y_to {
+            return Err(ParseError::at(17, data, token(3), "The y range is reversed"));
+        }
+        if x_from < 0 {
+            return Err(ParseError::at(17, data, token(1), "Expected the target right of the launcher"));
+        }
+        if y_to >= 0 {
+            return Err(ParseError::at(17, data, token(4), "Expected the target below the launcher"));
+        }
+        return Ok(Target{start: (x_from, y_to),
+                         end:   (x_to, y_from)});
+    }
+
+    fn part1(target: &Target) -> Result<Answer, NoAnswer> {
+        let peak = find_solutions(target).values().max().copied();
+        return Ok(peak.ok_or_else(|| NoAnswer::new(17, "No velocity hits the target"))?.into());
+    }
+
+    fn part2(target: &Target) -> Result<Answer, NoAnswer> {
+        return Ok(find_solutions(target).len().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unsupported_targets() {
+        let error = |data: &str| {
+            let error = Day17::parse(data).unwrap_err();
+            return (error.column, error.reason);
+        };
+        assert_eq!(error("target area: x=20..30, y=-5..-10"), (26, "The y range is reversed".to_string()));
+        assert_eq!(error("target area: x=30..20, y=-10..-5"), (16, "The x range is reversed".to_string()));
+        assert_eq!(error("target area: x=-30..-20, y=-10..-5"), (16, "Expected the target right of the launcher".to_string()));
+        assert_eq!(error("target area: x=20..30, y=-10..5"), (31, "Expected the target below the launcher".to_string()));
+
+        let target = Day17::parse("target area: x=20..30, y=-10..-5").unwrap();
+        assert_eq!(Day17::part1(&target), Ok(Answer::Int(45)));
+    }
+}
@@ -0,0 +1,234 @@
+use std::fmt;
+use std::hint::black_box;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+use std::time::Instant;
+
+use crate::bench::Throughput;
+use crate::error::ParseError;
+
+use super::{largest_magnitude, Snailfish, SnailfishNumber};
+
+// Pairs nested this deep explode
+const EXPLODE_DEPTH: usize = 4;
+
+//
+// A snailfish number as the list of its regular numbers from left to right,
+// each with the number of pairs enclosing it. Explosions and splits change
+// the list in place instead of rebuilding boxed pairs.
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct FlatNumber {
+    // (depth, value)
+    values: Vec<(usize, i32)>,
+}
+
+impl FlatNumber {
+    //
+    // Explodes every pair that needs to from the index on. The leftmost
+    // regular number nested deeper than EXPLODE_DEPTH that is followed by
+    // one at the same depth starts the leftmost exploding pair.
+    //
+    fn explode_from(&mut self, start: usize) {
+        let mut i = start;
+        while i + 1 < self.values.len() {
+            let ((depth, left), (next_depth, right)) = (self.values[i], self.values[i + 1]);
+            if depth <= EXPLODE_DEPTH || depth != next_depth {
+                i += 1;
+                continue;
+            }
+            if i > 0 {
+                self.values[i - 1].1 += left;
+            }
+            if i + 2 < self.values.len() {
+                self.values[i + 2].1 += right;
+            }
+            self.values[i] = (depth - 1, 0);
+            self.values.remove(i + 1);
+            // The 0 may form an exploding pair with the number before it
+            i = i.saturating_sub(1);
+        }
+    }
+
+    // Splits the leftmost number of 10 or more, returns its index
+    fn split(&mut self) -> Option<usize> {
+        let i = self.values.iter().position(|(_, value)| *value >= 10)?;
+        let (depth, value) = self.values[i];
+        self.values[i] = (depth + 1, value / 2);
+        self.values.insert(i + 1, (depth + 1, value - value / 2));
+        return Some(i);
+    }
+}
+
+impl Snailfish for FlatNumber {
+    fn join(&self, other: &FlatNumber) -> FlatNumber {
+        let values = self.values.iter().chain(other.values.iter()).map(|(depth, value)| (depth + 1, *value)).collect();
+        return FlatNumber{values: values};
+    }
+
+    fn reduce_in_place(&mut self) {
+        let mut start = 0;
+        loop {
+            self.explode_from(start);
+            match self.split() {
+                // Only the new pair can explode
+                Some(i) => start = i.saturating_sub(1),
+                None => return,
+            }
+        }
+    }
+
+    // Combines neighbours at the same depth from left to right, as pairs close
+    fn magnitude(&self) -> i64 {
+        let mut stack: Vec<(usize, i64)> = Vec::new();
+        for (depth, value) in &self.values {
+            let mut top = (*depth, *value as i64);
+            while let Some(&(depth, left)) = stack.last() {
+                if depth != top.0 {
+                    break;
+                }
+                stack.pop();
+                top = (depth - 1, left * 3 + top.1 * 2);
+            }
+            stack.push(top);
+        }
+        return stack.first().map(|(_, magnitude)| *magnitude).unwrap_or(0);
+    }
+}
+
+impl From<&SnailfishNumber> for FlatNumber {
+    fn from(number: &SnailfishNumber) -> Self {
+        fn flatten(number: &SnailfishNumber, depth: usize, values: &mut Vec<(usize, i32)>) {
+            match number {
+                SnailfishNumber::Number(value) => values.push((depth, *value)),
+                SnailfishNumber::Pair(pair) => {
+                    flatten(&pair.left, depth + 1, values);
+                    flatten(&pair.right, depth + 1, values);
+                }
+            }
+        }
+
+        let mut values = Vec::new();
+        flatten(number, 0, &mut values);
+        return FlatNumber{values: values};
+    }
+}
+
+impl FromStr for FlatNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<FlatNumber, ParseError> {
+        return Ok(FlatNumber::from(&s.parse::<SnailfishNumber>()?));
+    }
+}
+
+impl fmt::Display for FlatNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_at(f: &mut fmt::Formatter<'_>, values: &[(usize, i32)], i: &mut usize, depth: usize) -> fmt::Result {
+            let (value_depth, value) = values[*i];
+            if value_depth == depth {
+                *i += 1;
+                return write!(f, "{}", value);
+            }
+            write!(f, "[")?;
+            write_at(f, values, i, depth + 1)?;
+            write!(f, ",")?;
+            write_at(f, values, i, depth + 1)?;
+            return write!(f, "]");
+        }
+
+        return write_at(f, &self.values, &mut 0, 0);
+    }
+}
+
+impl Add for &FlatNumber {
+    type Output = FlatNumber;
+
+    fn add(self, other: &FlatNumber) -> FlatNumber {
+        let mut sum = self.join(other);
+        sum.reduce_in_place();
+        return sum;
+    }
+}
+
+impl Add for FlatNumber {
+    type Output = FlatNumber;
+
+    fn add(self, other: FlatNumber) -> FlatNumber {
+        return &self + &other;
+    }
+}
+
+// Like the sum of SnailfishNumbers, an empty sum panics
+impl<'a> Sum<&'a FlatNumber> for FlatNumber {
+    fn sum<I: Iterator<Item = &'a FlatNumber>>(mut iter: I) -> FlatNumber {
+        let first = iter.next().expect("Cannot sum no snailfish numbers").reduce();
+        return iter.fold(first, |sum, number| &sum + number);
+    }
+}
+
+impl Sum for FlatNumber {
+    fn sum<I: Iterator<Item = FlatNumber>>(iter: I) -> FlatNumber {
+        let numbers: Vec<FlatNumber> = iter.collect();
+        return numbers.iter().sum();
+    }
+}
+
+//
+// Times the part 2 search for the largest magnitude of a pair on both
+// representations, the given number of times each
+//
+pub fn benchmark(numbers: &[SnailfishNumber], rounds: usize) -> Vec<Throughput> {
+    fn measure<N: Snailfish + Sync>(name: &str, numbers: &[N], rounds: usize) -> (Throughput, Option<i64>) {
+        let mut largest = None;
+        let start = Instant::now();
+        for _round in 0 .. rounds {
+            largest = black_box(largest_magnitude(black_box(numbers)));
+        }
+        let sums = numbers.len() * numbers.len().saturating_sub(1) * rounds;
+        return (Throughput{name: name.to_string(), items: sums, elapsed: start.elapsed()}, largest);
+    }
+
+    let flat: Vec<FlatNumber> = numbers.iter().map(FlatNumber::from).collect();
+    let (tree, tree_largest) = measure("boxed tree", numbers, rounds);
+    let (flat, flat_largest) = measure("flat", &flat, rounds);
+    assert_eq!(tree_largest, flat_largest, "The representations disagree");
+    return vec![tree, flat];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_tree() {
+        let lines = ["[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]", "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+                     "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]", "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+                     "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]", "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]"];
+        let tree: Vec<SnailfishNumber> = lines.iter().map(|l| l.parse().unwrap()).collect();
+        let flat: Vec<FlatNumber> = lines.iter().map(|l| l.parse().unwrap()).collect();
+        for (tree, flat) in tree.iter().zip(&flat) {
+            assert_eq!(flat.to_string(), tree.to_string());
+        }
+
+        let sum = flat.iter().sum::<FlatNumber>();
+        assert_eq!(sum.to_string(), tree.iter().sum::<SnailfishNumber>().to_string());
+        assert_eq!(sum.magnitude(), tree.iter().sum::<SnailfishNumber>().magnitude());
+        assert_eq!(largest_magnitude(&flat), largest_magnitude(&tree));
+    }
+
+    #[test]
+    fn reduces_in_place() {
+        let mut number: FlatNumber = "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]".parse().unwrap();
+        number.reduce_in_place();
+        assert_eq!(number.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+        // Deeper than any sum of reduced numbers gets
+        let mut number: FlatNumber = "[[[[[[1,2],3],4],5],6],7]".parse().unwrap();
+        number.reduce_in_place();
+        let mut tree: SnailfishNumber = "[[[[[[1,2],3],4],5],6],7]".parse().unwrap();
+        tree.reduce_in_place();
+        assert_eq!(number.to_string(), tree.to_string());
+        assert_eq!("[[1,2],[[3,4],5]]".parse::<FlatNumber>().unwrap().magnitude(), 143);
+    }
+}
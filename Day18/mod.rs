@@ -0,0 +1,470 @@
+use std::iter::{self, Sum};
+use std::ops::Add;
+use std::str::{self, FromStr};
+use std::cmp;
+use std::thread;
+
+use std::fmt;
+
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub mod flat;
+pub mod trace;
+
+use flat::FlatNumber;
+use trace::Side;
+
+pub struct Day18;
+
+#[derive(Eq)] #[derive(PartialEq)] #[derive(Clone)]
+pub enum SnailfishNumber {
+    Number(i32),
+    Pair(Box<NumberPair>),
+}
+
+impl fmt::Debug for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnailfishNumber::Number(val) => f.write_str(format!("{}", val).as_str()),
+            SnailfishNumber::Pair(pair) => pair.fmt(f),
+        }
+    }
+}
+
+// The bracket syntax of the puzzle, e.g. "[[1,2],3]"
+impl fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnailfishNumber::Number(val) => write!(f, "{}", val),
+            SnailfishNumber::Pair(pair) => write!(f, "[{},{}]", pair.left, pair.right),
+        }
+    }
+}
+
+struct Explosion {
+    // What is left to add to the neighbours
+    left: i32,
+    right: i32,
+    // The pair as it exploded
+    pair: (i32, i32),
+}
+
+impl SnailfishNumber {
+    fn propogate_from_right(&mut self, numb: i32) -> i32{
+        match self {
+            SnailfishNumber::Number(v) => {
+                *self = SnailfishNumber::Number(*v + numb);
+                return 0;
+            },
+            SnailfishNumber::Pair(pair) => {
+                return pair.right.propogate_from_right(numb);
+            }
+        };
+    }
+
+    fn propogate_from_left(&mut self, numb: i32) -> i32 {
+        match self {
+            SnailfishNumber::Number(v) => {
+                *self = SnailfishNumber::Number(*v + numb);
+                return 0;
+            },
+            SnailfishNumber::Pair(pair) => {
+                return pair.left.propogate_from_left(numb);
+            }
+        };
+    }
+
+    //
+    // Explodes the leftmost pair nested inside four pairs, path is that of
+    // this number and ends up as the path of the exploded pair
+    //
+    fn explode(&mut self, path: &mut Vec<Side>) -> Option<Explosion> {
+        match self {
+            SnailfishNumber::Pair(pair) => {
+                if path.len() >= 4 {
+                    let left = match pair.left { SnailfishNumber::Number(val) => Some(val), _ => None };
+                    let right = match pair.right { SnailfishNumber::Number(val) => Some(val), _ => None };
+
+                    if [left, right].iter().all(|v| v.is_some() ) {
+                        *self = SnailfishNumber::Number(0);
+                        return Some(Explosion{left: left.unwrap(), right: right.unwrap(), pair: (left.unwrap(), right.unwrap())});
+                    }
+                }
+                return pair.explode(path);
+            }
+            _ => { return None; }
+        }
+
+    }
+
+    //
+    // Splits the leftmost number of 10 or more and returns it, path ends up
+    // as its path like for explode
+    //
+    fn split(&mut self, path: &mut Vec<Side>) -> Option<i32> {
+        match self {
+            SnailfishNumber::Pair(pair) => {
+                return pair.split(path);
+            }
+            SnailfishNumber::Number(val) => {
+                if *val >= 10 {
+                    let value = *val;
+                    *self = SnailfishNumber::Pair(NumberPair::new(
+                        SnailfishNumber::Number(value/2),
+                        SnailfishNumber::Number(value/2 + value%2)));
+                    return Some(value);
+                }
+                return None;
+            }
+        }
+    }
+}
+
+//
+// What both representations of snailfish numbers, the boxed tree and the
+// flat FlatNumber, can do
+//
+pub trait Snailfish: Clone {
+    // The pair of both numbers, without reducing it
+    fn join(&self, other: &Self) -> Self;
+
+    // Explodes and splits until neither applies
+    fn reduce_in_place(&mut self);
+
+    fn magnitude(&self) -> i64;
+
+    fn reduce(&self) -> Self {
+        let mut res = self.clone();
+        res.reduce_in_place();
+        return res;
+    }
+}
+
+impl Snailfish for SnailfishNumber {
+    fn join(&self, other: &SnailfishNumber) -> SnailfishNumber {
+        return SnailfishNumber::Pair(NumberPair::new(
+            self.clone(),
+            other.clone()));
+    }
+
+    fn reduce_in_place(&mut self) {
+        let mut path = Vec::new();
+        let mut any_action = true;
+        while any_action {
+            any_action = false;
+            while self.explode(&mut path).is_some() {
+                any_action = true;
+                path.clear();
+            }
+            if self.split(&mut path).is_some() {
+                any_action = true;
+                path.clear();
+            }
+        }
+    }
+
+    fn magnitude(&self) -> i64 {
+        match self {
+            SnailfishNumber::Pair(pair) => {
+                return pair.left.magnitude() * 3 + pair.right.magnitude() * 2;
+            }
+            SnailfishNumber::Number(val) => {
+                return *val as i64;
+            }
+        }
+    }
+}
+
+// Snailfish addition, joining both numbers and reducing the result
+impl Add for &SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: &SnailfishNumber) -> SnailfishNumber {
+        let mut sum = self.join(other);
+        sum.reduce_in_place();
+        return sum;
+    }
+}
+
+impl Add for SnailfishNumber {
+    type Output = SnailfishNumber;
+
+    fn add(self, other: SnailfishNumber) -> SnailfishNumber {
+        return &self + &other;
+    }
+}
+
+//
+// Adds up the numbers in order, as the homework does. Snailfish addition has
+// no neutral number, so an empty sum panics.
+//
+impl<'a> Sum<&'a SnailfishNumber> for SnailfishNumber {
+    fn sum<I: Iterator<Item = &'a SnailfishNumber>>(mut iter: I) -> SnailfishNumber {
+        let first = iter.next().expect("Cannot sum no snailfish numbers").reduce();
+        return iter.fold(first, |sum, number| &sum + number);
+    }
+}
+
+impl Sum for SnailfishNumber {
+    fn sum<I: Iterator<Item = SnailfishNumber>>(iter: I) -> SnailfishNumber {
+        let numbers: Vec<SnailfishNumber> = iter.collect();
+        return numbers.iter().sum();
+    }
+}
+
+#[derive(Eq)] #[derive(PartialEq)] #[derive(Clone)]
+pub struct NumberPair {
+    pub left: SnailfishNumber,
+    pub right: SnailfishNumber,
+}
+
+impl fmt::Debug for NumberPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[").unwrap();
+        self.left.fmt(f).unwrap();
+        f.write_str(",").unwrap();
+        self.right.fmt(f).unwrap();
+        f.write_str("]").unwrap();
+        return fmt::Result::Ok(());
+    }
+}
+
+impl NumberPair {
+    pub fn new(left: SnailfishNumber, right: SnailfishNumber) -> Box<NumberPair> {
+        return Box::new(NumberPair{left: left, right: right});
+    }
+
+    fn explode(&mut self, path: &mut Vec<Side>) -> Option<Explosion> {
+        path.push(Side::Left);
+        if let Some(mut exp) = self.left.explode(path) {
+            exp.right = self.right.propogate_from_left(exp.right);
+            return Some(exp);
+        }
+        path.pop();
+
+        path.push(Side::Right);
+        if let Some(mut exp) = self.right.explode(path) {
+            exp.left = self.left.propogate_from_right(exp.left);
+            return Some(exp);
+        }
+        path.pop();
+
+        return None;
+    }
+
+    fn split(&mut self, path: &mut Vec<Side>) -> Option<i32> {
+        for side in [Side::Left, Side::Right] {
+            path.push(side);
+            let number = if side == Side::Left { &mut self.left } else { &mut self.right };
+            if let Some(value) = number.split(path) {
+                return Some(value);
+            }
+            path.pop();
+        }
+        return None;
+    }
+}
+
+type CharIter<'a> = iter::Peekable<str::CharIndices<'a>>;
+
+fn expect_char(line: &str, iter: &mut CharIter, expected: char) -> Result<(), ParseError> {
+    return match iter.next() {
+        Some((_, c)) if c == expected => Ok(()),
+        Some((i, _)) => Err(ParseError::at(18, line, error::char_at(line, i), &format!("Expected '{}'", expected))),
+        None => Err(ParseError::at_end(18, line, &format!("Expected '{}'", expected))),
+    };
+}
+
+fn parse_pair(line: &str, iter: &mut CharIter) -> Result<SnailfishNumber, ParseError> {
+    let numb1 = parse_element(line, iter)?;
+    expect_char(line, iter, ',')?;
+    let numb2 = parse_element(line, iter)?;
+    expect_char(line, iter, ']')?;
+    return Ok(SnailfishNumber::Pair(NumberPair::new(numb1, numb2)));
+}
+
+fn parse_single(line: &str, iter: &mut CharIter) -> Result<SnailfishNumber, ParseError> {
+    let start = iter.peek().map(|(i, _)| *i).unwrap_or(line.len());
+    let mut end = start;
+    while let Some((i, c)) = iter.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        end = i + 1;
+        iter.next();
+    }
+    if start == end {
+        return Err(ParseError::at(18, line, error::char_at(line, start), "Expected a number or a pair"));
+    }
+    return Ok(SnailfishNumber::Number(error::parse_number(18, line, &line[start .. end])?));
+}
+
+fn parse_element(line: &str, iter: &mut CharIter) -> Result<SnailfishNumber, ParseError> {
+    if let Some((_, '[')) = iter.peek() {
+        iter.next();
+        return parse_pair(line, iter);
+    }
+    return parse_single(line, iter);
+}
+
+//
+// Parses a single snailfish number, errors are located as if line was the
+// whole input
+//
+pub fn parse_snailfish_number(line: &str) -> Result<SnailfishNumber, ParseError> {
+    let mut iter = line.char_indices().peekable();
+    let number = parse_element(line, &mut iter)?;
+    if let Some((i, _)) = iter.next() {
+        return Err(ParseError::at(18, line, &line[i ..], "Unexpected trailing characters"));
+    }
+    return Ok(number);
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<SnailfishNumber, ParseError> {
+        return parse_snailfish_number(s);
+    }
+}
+
+//
+// The two different numbers whose sum has the largest magnitude, first
+// added to second. Of equal magnitudes the pair with the smallest first,
+// then second index wins.
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct BestPair<N> {
+    pub first: usize,
+    pub second: usize,
+    pub sum: N,
+    pub magnitude: i64,
+}
+
+// Searches on as many threads as there are cores, None for less than two numbers
+pub fn best_pair<N: Snailfish + Sync>(numbers: &[N]) -> Option<BestPair<N>> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    return best_pair_on(numbers, threads);
+}
+
+//
+// Searches on the given number of threads. Thread t takes the pairs whose
+// first number is t, t + threads, t + 2 * threads and so on, each thread
+// gets its share of long and short numbers.
+//
+pub fn best_pair_on<N: Snailfish + Sync>(numbers: &[N], threads: usize) -> Option<BestPair<N>> {
+    let threads = threads.clamp(1, numbers.len().max(1));
+    let best = thread::scope(|scope| {
+        let searches: Vec<_> = (0 .. threads).map(|t| scope.spawn(move || search_pairs(numbers, t, threads))).collect();
+        return searches.into_iter()
+                       .filter_map(|search| search.join().unwrap())
+                       .max_by_key(|&(first, second, magnitude)| (magnitude, cmp::Reverse((first, second))));
+    });
+
+    let (first, second, magnitude) = best?;
+    let mut sum = numbers[first].join(&numbers[second]);
+    sum.reduce_in_place();
+    return Some(BestPair{first: first, second: second, sum: sum, magnitude: magnitude});
+}
+
+// The best (first, second, magnitude) of the pairs whose first index is start, start + step, ...
+fn search_pairs<N: Snailfish>(numbers: &[N], start: usize, step: usize) -> Option<(usize, usize, i64)> {
+    let mut best: Option<(usize, usize, i64)> = None;
+    for i in (start .. numbers.len()).step_by(step) {
+        for j in 0 .. numbers.len() {
+            if i != j {
+                let mut sum = numbers[i].join(&numbers[j]);
+                sum.reduce_in_place();
+                let magnitude = sum.magnitude();
+                if best.is_none_or(|(_, _, largest)| magnitude > largest) {
+                    best = Some((i, j, magnitude));
+                }
+            }
+        }
+    }
+    return best;
+}
+
+// The largest magnitude of the sum of two different numbers in either order, None for less than two numbers
+pub fn largest_magnitude<N: Snailfish + Sync>(numbers: &[N]) -> Option<i64> {
+    return best_pair(numbers).map(|pair| pair.magnitude);
+}
+
+impl Solution for Day18 {
+    type Input = Vec<SnailfishNumber>;
+
+    fn parse(data: &str) -> Result<Vec<SnailfishNumber>, ParseError> {
+        let mut result = Vec::new();
+        for (index, line) in data.lines().enumerate() {
+            let number = line.parse::<SnailfishNumber>().map_err(|e| ParseError{line: index + 1, ..e})?;
+            result.push(number);
+        }
+        if result.is_empty() {
+            return Err(ParseError::at_end(18, data, "No snailfish numbers"));
+        }
+        return Ok(result);
+    }
+
+    fn part1(numbers: &Vec<SnailfishNumber>) -> Result<Answer, NoAnswer> {
+        // Summing no numbers panics
+        if numbers.is_empty() {
+            return Err(NoAnswer::new(18, "No snailfish numbers to add"));
+        }
+        return Ok(numbers.iter().sum::<SnailfishNumber>().magnitude().into());
+    }
+
+    fn part2(numbers: &Vec<SnailfishNumber>) -> Result<Answer, NoAnswer> {
+        // The flat numbers reduce a lot faster than the boxed ones
+        let numbers: Vec<FlatNumber> = numbers.iter().map(FlatNumber::from).collect();
+        let largest = largest_magnitude(&numbers).ok_or_else(|| NoAnswer::new(18, "Part 2 needs two snailfish numbers"))?;
+        return Ok(largest.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(s: &str) -> SnailfishNumber {
+        return s.parse().unwrap();
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for s in ["[1,2]", "[[1,9],[8,5]]", "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]", "12"] {
+            assert_eq!(number(s).to_string(), s);
+        }
+        let error = "[1,2".parse::<SnailfishNumber>().unwrap_err();
+        assert_eq!((error.column, error.reason.as_str()), (5, "Expected ']'"));
+        let error = "[1;2]".parse::<SnailfishNumber>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (3, ";"));
+        assert!("[1,2]]".parse::<SnailfishNumber>().is_err());
+    }
+
+    #[test]
+    fn adds_and_reduces() {
+        assert_eq!(number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]"), number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
+        let numbers: Vec<SnailfishNumber> = (1 ..= 6).map(|i| number(&format!("[{},{}]", i, i))).collect();
+        assert_eq!(numbers.iter().sum::<SnailfishNumber>(), number("[[[[5,0],[7,4]],[5,5]],[6,6]]"));
+        assert_eq!(numbers.into_iter().take(4).sum::<SnailfishNumber>(), number("[[[[1,1],[2,2]],[3,3]],[4,4]]"));
+        assert_eq!(Day18::part1(&Vec::new()).unwrap_err().reason, "No snailfish numbers to add");
+    }
+
+    #[test]
+    fn finds_the_best_pair() {
+        let numbers = Day18::parse(include_str!("sample_input")).unwrap();
+        for threads in [1, 3, 16] {
+            let best = best_pair_on(&numbers, threads).unwrap();
+            assert_eq!((best.first, best.second, best.magnitude), (8, 0, 3993));
+            assert_eq!(best.sum, number("[[[[7,8],[6,6]],[[6,0],[7,7]]],[[[7,8],[8,8]],[[7,9],[0,6]]]]"));
+        }
+        // Both orders of [1,1] and [1,1] tie
+        let numbers: Vec<SnailfishNumber> = ["[1,1]", "[0,0]", "[1,1]"].iter().map(|s| number(s)).collect();
+        let best = best_pair_on(&numbers, 2).unwrap();
+        assert_eq!((best.first, best.second), (0, 2));
+        assert_eq!(best_pair(&numbers[.. 1]), None);
+        assert_eq!(largest_magnitude(&numbers[.. 1]), None);
+        assert_eq!(Day18::part2(&numbers[.. 1].to_vec()).unwrap_err().reason, "Part 2 needs two snailfish numbers");
+    }
+}
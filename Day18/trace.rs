@@ -0,0 +1,108 @@
+use std::fmt;
+
+use super::{Snailfish, SnailfishNumber};
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+// The way down from the outermost pair, e.g. "LRR", or "top" for the number itself
+pub fn path_to_string(path: &[Side]) -> String {
+    if path.is_empty() {
+        return "top".to_string();
+    }
+    return path.iter().map(|side| if *side == Side::Left { 'L' } else { 'R' }).collect();
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Action {
+    // The pair of regular numbers at the path exploded
+    Explode{path: Vec<Side>, left: i32, right: i32},
+    // The regular number at the path split
+    Split{path: Vec<Side>, value: i32},
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Explode{path, left, right} => write!(f, "explode [{},{}] at {}", left, right, path_to_string(path)),
+            Action::Split{path, value} => write!(f, "split {} at {}", value, path_to_string(path)),
+        }
+    }
+}
+
+// An action taken while reducing and the number it left
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct Step {
+    pub action: Action,
+    pub number: SnailfishNumber,
+}
+
+impl SnailfishNumber {
+    // The number at the path, None if the path leads past a regular number
+    pub fn at(&self, path: &[Side]) -> Option<&SnailfishNumber> {
+        let mut number = self;
+        for side in path {
+            number = match (number, side) {
+                (SnailfishNumber::Pair(pair), Side::Left) => &pair.left,
+                (SnailfishNumber::Pair(pair), Side::Right) => &pair.right,
+                (SnailfishNumber::Number(_), _) => return None,
+            };
+        }
+        return Some(number);
+    }
+
+    // Reduces the number like reduce_in_place, recording every step
+    pub fn reduce_traced(&mut self) -> Vec<Step> {
+        let mut steps = Vec::new();
+        loop {
+            let mut path = Vec::new();
+            let action = if let Some(explosion) = self.explode(&mut path) {
+                let (left, right) = explosion.pair;
+                Action::Explode{path: path, left: left, right: right}
+            } else if let Some(value) = self.split(&mut path) {
+                Action::Split{path: path, value: value}
+            } else {
+                return steps;
+            };
+            steps.push(Step{action: action, number: self.clone()});
+        }
+    }
+
+    // The sum of both numbers with the steps reducing it
+    pub fn add_traced(&self, other: &SnailfishNumber) -> (SnailfishNumber, Vec<Step>) {
+        let mut sum = self.join(other);
+        let steps = sum.reduce_traced();
+        return (sum, steps);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traces_the_example() {
+        let a: SnailfishNumber = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+        let (sum, steps) = a.add_traced(&"[1,1]".parse().unwrap());
+        let lines: Vec<String> = steps.iter().map(|step| format!("{}: {}", step.action, step.number)).collect();
+        assert_eq!(lines, ["explode [4,3] at LLLL: [[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+                           "explode [8,4] at LRRL: [[[[0,7],4],[15,[0,13]]],[1,1]]",
+                           "split 15 at LRL: [[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+                           "split 13 at LRRR: [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+                           "explode [6,7] at LRRR: [[[[0,7],4],[[7,8],[6,0]]],[8,1]]"]);
+        assert_eq!(sum, steps.last().unwrap().number);
+        assert_eq!(sum, &a + &"[1,1]".parse().unwrap());
+    }
+
+    #[test]
+    fn paths() {
+        let number: SnailfishNumber = "[[1,2],3]".parse().unwrap();
+        assert_eq!(number.at(&[Side::Left, Side::Right]), Some(&SnailfishNumber::Number(2)));
+        assert_eq!(number.at(&[Side::Right, Side::Left]), None);
+        let mut number: SnailfishNumber = "11".parse().unwrap();
+        assert_eq!(number.reduce_traced()[0].action.to_string(), "split 11 at top");
+    }
+}
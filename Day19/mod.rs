@@ -0,0 +1,352 @@
+use std::ops;
+use std::cmp;
+
+use std::collections::HashSet;
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub mod rotation;
+
+use rotation::Rotation3;
+
+pub struct Day19;
+
+#[derive(Clone)] #[derive(Copy)] #[derive(Hash)]  #[derive(PartialEq)] #[derive(Eq)] #[derive(Debug)]
+pub struct Pos {
+    pub vec: [i32; 3],
+}
+
+impl Pos {
+    pub fn new(x: i32, y: i32, z: i32) -> Self {
+        return Pos{vec: [x, y, z]};
+    }
+}
+
+impl ops::Add<Pos> for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Pos) -> Pos {
+        return Pos::new(self.vec[0] + rhs.vec[0], self.vec[1] + rhs.vec[1], self.vec[2] + rhs.vec[2]);
+    }
+}
+
+impl ops::Sub<Pos> for Pos {
+    type Output = Pos;
+
+    fn sub(self, rhs: Pos) -> Pos {
+        return Pos::new(self.vec[0] - rhs.vec[0], self.vec[1] - rhs.vec[1], self.vec[2] - rhs.vec[2]);
+    }
+}
+
+#[derive(Clone)] #[derive(Debug)]
+pub struct Scanner {
+    pub id: u32,
+    pub pos: Option<Pos>,
+    pub beacons: Vec<Pos>,
+    // Turns the beacons as reported into the orientation of the first scanner
+    pub rotation: Rotation3,
+}
+
+impl Scanner {
+    fn new(id: u32) -> Self{
+        return Scanner{id: id, pos: None, beacons: Vec::new(), rotation: Rotation3::IDENTITY};
+    }
+
+    fn orient(&self, rotation: &Rotation3) -> Scanner {
+        let mut new_scanner = Scanner::new(self.id);
+        new_scanner.pos = self.pos;
+        new_scanner.rotation = rotation.compose(&self.rotation);
+        for beacon in &self.beacons {
+            new_scanner.beacons.push(rotation.apply(*beacon));
+        }
+        return new_scanner;
+    }
+
+    fn orientations(&self) -> Vec<Scanner> {
+        return Rotation3::all().iter().map(|rotation| self.orient(rotation)).collect();
+    }
+
+    fn position(&self, pos: Pos) -> Scanner {
+        let mut new_scanner = Scanner::new(self.id);
+        new_scanner.pos = Some(pos);
+        new_scanner.beacons = self.beacons.clone();
+        new_scanner.rotation = self.rotation;
+
+        return new_scanner;
+    }
+
+    fn try_position_with(&self, other: &Scanner, config: &AlignConfig) -> Vec<ScannerGroup> {
+        let mut scanner_groups: Vec<ScannerGroup> = Vec::new();
+        let self_pos = self.pos.unwrap();
+
+        for scanner in other.orientations() {
+            let mut offset_candidates: HashMap<Pos, usize> = HashMap::new();
+            for beacon in &self.beacons {
+                for other_beacon in &scanner.beacons {
+                    let offset = (*beacon + self_pos) - *other_beacon;
+                    offset_candidates.insert(offset, offset_candidates.get(&offset).unwrap_or(&0) + 1);
+                }
+            }
+            let filtered_candidates = offset_candidates.iter()
+                          .filter(|(_ ,v)| **v >= config.overlap).map(|(k, _)| *k).collect::<Vec<Pos>>();
+            'offset_checker : for candidate in &filtered_candidates {
+                let positioned_scanner = scanner.position(*candidate);
+                let mut real_positions: HashSet<Pos> = HashSet::new();
+
+                for beacon in &self.beacons {
+                    real_positions.insert(*beacon + self_pos);
+                }
+                for beacon in &positioned_scanner.beacons {
+                    let abs_position = *beacon + positioned_scanner.pos.unwrap();
+                    let rel_position = abs_position - self_pos;
+                    if !rel_position.vec.iter().any(|v| v.abs() > config.range) && !real_positions.contains(&abs_position) {
+                        // A beacon that should be seen is not
+                        continue 'offset_checker;
+                    }
+                    real_positions.insert(abs_position);
+                }
+                let sg = ScannerGroup{scanners: (self.clone(), positioned_scanner), beacons_abs: real_positions};
+                scanner_groups.push(sg);
+            }
+        }
+        return scanner_groups;
+    }
+}
+
+#[derive(Debug)] #[derive(Clone)]
+pub struct ScannerGroup {
+    scanners: (Scanner, Scanner),
+    beacons_abs: HashSet<Pos>,
+}
+
+impl ScannerGroup {
+    pub fn get_overlap_count(&self) -> usize {
+        return [&self.scanners.0, &self.scanners.1].iter().map(|&s| s.beacons.len() ).sum::<usize>()
+                - self.beacons_abs.len();
+    }
+}
+
+
+// What it takes for two scanners to be aligned
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct AlignConfig {
+    // The number of beacons both need to see
+    pub overlap: usize,
+    // How far a scanner sees along each axis, every beacon of the other
+    // scanner within it has to be one it sees
+    pub range: i32,
+}
+
+impl Default for AlignConfig {
+    fn default() -> Self {
+        return AlignConfig{overlap: 12, range: 1000};
+    }
+}
+
+// Where a scanner is relative to the first one and how it is turned
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct Placement {
+    pub id: u32,
+    pub position: Pos,
+    // Turns the beacons the scanner reports into the orientation of the first scanner
+    pub rotation: Rotation3,
+}
+
+#[derive(Debug)] #[derive(Clone)]
+pub struct Alignment {
+    // The scanners that could be aligned, in the order they were given
+    pub placements: Vec<Placement>,
+    // The ids of the others
+    pub unaligned: Vec<u32>,
+    // Every beacon the aligned scanners see, relative to the first scanner
+    pub beacons: HashSet<Pos>,
+}
+
+impl Alignment {
+    pub fn is_complete(&self) -> bool {
+        return self.unaligned.is_empty();
+    }
+
+    // E.g. "Aligned 3 of 5 scanners, could not align scanners 2 and 4"
+    pub fn report(&self) -> String {
+        let total = self.placements.len() + self.unaligned.len();
+        let ids: Vec<String> = self.unaligned.iter().map(|id| id.to_string()).collect();
+        return match ids.as_slice() {
+            [] => format!("Aligned all {} scanners", total),
+            [id] => format!("Aligned {} of {} scanners, could not align scanner {}", self.placements.len(), total, id),
+            [ids @ .., last] => format!("Aligned {} of {} scanners, could not align scanners {} and {}",
+                                        self.placements.len(), total, ids.join(", "), last),
+        };
+    }
+}
+
+//
+// Positions every scanner it can relative to the first one. Scanners are
+// aligned with ones that already are, those that share too few beacons with
+// any of them are reported as unaligned. No scanners give an empty alignment.
+//
+pub fn align(scanners: &[Scanner], config: &AlignConfig) -> Alignment {
+    let mut scanners = scanners.to_vec();
+    if let Some(first) = scanners.first_mut() {
+        first.pos = Some(Pos::new(0, 0, 0));
+    }
+
+    let mut checked = HashSet::new();
+    'pairing_loop: loop {
+        let mut positioned = Vec::new();
+        let mut unpositioned = Vec::new();
+        for (i, scanner) in scanners.iter().enumerate() {
+            if scanner.pos.is_some() {
+                if !checked.contains(&i) {
+                   positioned.push(i);
+                }
+            } else {
+                unpositioned.push(i);
+            }
+        }
+        // Done, or no scanner left to align the rest with
+        if unpositioned.is_empty() || positioned.is_empty() {
+            break;
+        }
+        for i in &positioned {
+            let mut any_new_positioned = false;
+            for j in &unpositioned {
+                let new_pairs = scanners[*i].try_position_with(&scanners[*j], config);
+                if !new_pairs.is_empty() {
+                    scanners[*j] = new_pairs[0].scanners.1.clone();
+                    any_new_positioned = true;
+                }
+
+            }
+            checked.insert(*i);
+            if any_new_positioned {
+                continue 'pairing_loop;
+            }
+        }
+
+    }
+
+    let mut alignment = Alignment{placements: Vec::new(), unaligned: Vec::new(), beacons: HashSet::new()};
+    for scanner in &scanners {
+        let pos = match scanner.pos {
+            Some(pos) => pos,
+            None => {
+                alignment.unaligned.push(scanner.id);
+                continue;
+            }
+        };
+        alignment.beacons.extend(scanner.beacons.iter().map(|beacon| *beacon + pos));
+        alignment.placements.push(Placement{id: scanner.id, position: pos, rotation: scanner.rotation});
+    }
+    return alignment;
+}
+
+// The alignment both parts need, the answers only make sense for all scanners
+fn align_all(scanners: &[Scanner]) -> Result<Alignment, NoAnswer> {
+    let alignment = align(scanners, &AlignConfig::default());
+    if !alignment.is_complete() {
+        return Err(NoAnswer::new(19, &alignment.report()));
+    }
+    return Ok(alignment);
+}
+
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
+
+    fn parse(data: &str) -> Result<Vec<Scanner>, ParseError> {
+        let header_re = Regex::new(r"^--- scanner ([-\d]+) ---$").unwrap();
+
+        let mut scanners: Vec<Scanner> = Vec::new();
+        let mut scanner: Option<Scanner> = None;
+        for line in data.lines() {
+            if let Some(cap) = header_re.captures(line) {
+                scanner = Some(Scanner::new(error::parse_number(19, data, cap.get(1).unwrap().as_str())?));
+            } else if line.is_empty() {
+                if let Some(complete) = scanner.take() {
+                    scanners.push(complete);
+                }
+            } else {
+                let current = scanner.as_mut().ok_or_else(|| ParseError::at(19, data, line, "Beacon outside of a scanner"))?;
+                let splits: Vec<&str> = line.split(',').collect();
+                if splits.len() != 3 {
+                    return Err(ParseError::at(19, data, line, "Expected \"x,y,z\""));
+                }
+                let beacon = Pos::new(error::parse_number(19, data, splits[0])?,
+                                      error::parse_number(19, data, splits[1])?,
+                                      error::parse_number(19, data, splits[2])?);
+                current.beacons.push(beacon);
+            }
+
+        }
+        if let Some(complete) = scanner {
+            scanners.push(complete);
+        }
+        if scanners.is_empty() {
+            return Err(ParseError::at_end(19, data, "No scanners"));
+        }
+        return Ok(scanners);
+    }
+
+    fn part1(scanners: &Vec<Scanner>) -> Result<Answer, NoAnswer> {
+        return Ok(align_all(scanners)?.beacons.len().into());
+    }
+
+    fn part2(scanners: &Vec<Scanner>) -> Result<Answer, NoAnswer> {
+        let positions: Vec<Pos> = align_all(scanners)?.placements.iter().map(|placement| placement.position).collect();
+
+        let mut biggest_dist = 0;
+        for i in 0 .. positions.len() {
+            for j in i + 1 .. positions.len() {
+                let manh_dist = (positions[i].vec[0] - positions[j].vec[0]).abs() +
+                                (positions[i].vec[1] - positions[j].vec[1]).abs() +
+                                (positions[i].vec[2] - positions[j].vec[2]).abs();
+                biggest_dist = cmp::max(biggest_dist, manh_dist);
+            }
+        }
+        return Ok(biggest_dist.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligns_the_sample() {
+        let scanners = Day19::parse(include_str!("sample_input")).unwrap();
+        let alignment = align(&scanners, &AlignConfig::default());
+        assert_eq!(alignment.report(), "Aligned all 5 scanners");
+        let positions: Vec<Pos> = alignment.placements.iter().map(|placement| placement.position).collect();
+        assert_eq!(positions, [Pos::new(0, 0, 0), Pos::new(68, -1246, -43), Pos::new(1105, -1205, 1229),
+                               Pos::new(-92, -2380, -20), Pos::new(-20, -1133, 1061)]);
+        assert_eq!(alignment.beacons.len(), 79);
+        for (placement, scanner) in alignment.placements.iter().zip(&scanners) {
+            for beacon in &scanner.beacons {
+                assert!(alignment.beacons.contains(&(placement.rotation.apply(*beacon) + placement.position)));
+            }
+        }
+    }
+
+    #[test]
+    fn reports_unaligned_scanners() {
+        let scanners = Day19::parse(include_str!("sample_input")).unwrap();
+        let alignment = align(&scanners, &AlignConfig{overlap: 13, ..AlignConfig::default()});
+        assert!(!alignment.is_complete());
+        assert_eq!(alignment.placements[0].rotation, Rotation3::IDENTITY);
+        let alignment = align(&scanners, &AlignConfig{overlap: 30, ..AlignConfig::default()});
+        assert_eq!(alignment.unaligned, [1, 2, 3, 4]);
+        assert_eq!(alignment.report(), "Aligned 1 of 5 scanners, could not align scanners 1, 2, 3 and 4");
+        assert_eq!(alignment.beacons.len(), scanners[0].beacons.len());
+        assert_eq!(Day19::part1(&scanners[.. 2].to_vec()), Ok(Answer::UInt(38)));
+        assert_eq!(Day19::part1(&vec![scanners[0].clone(), scanners[2].clone()]).unwrap_err().to_string(),
+                   "Day 19 has no answer: Aligned 1 of 2 scanners, could not align scanner 2");
+
+        let alignment = align(&[], &AlignConfig::default());
+        assert!(alignment.placements.is_empty() && alignment.beacons.is_empty());
+        assert_eq!(alignment.report(), "Aligned all 0 scanners");
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use super::Pos;
+
+//
+// A rotation by multiples of 90 degrees about the axes, as the integer
+// matrix turning a position. Every row and column holds a single 1 or -1 and
+// the determinant is 1, mirror images are not rotations.
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(Hash)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct Rotation3 {
+    matrix: [[i32; 3]; 3],
+}
+
+const PERMUTATIONS: [[usize; 3]; 6] = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+
+impl Rotation3 {
+    pub const IDENTITY: Rotation3 = Rotation3{matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]]};
+
+    // A quarter turn about each axis, counterclockwise looking down the axis
+    pub const QUARTER_X: Rotation3 = Rotation3{matrix: [[1, 0, 0], [0, 0, -1], [0, 1, 0]]};
+    pub const QUARTER_Y: Rotation3 = Rotation3{matrix: [[0, 0, 1], [0, 1, 0], [-1, 0, 0]]};
+    pub const QUARTER_Z: Rotation3 = Rotation3{matrix: [[0, -1, 0], [1, 0, 0], [0, 0, 1]]};
+
+    // None unless the matrix is one of the 24 rotations
+    pub fn from_matrix(matrix: [[i32; 3]; 3]) -> Option<Rotation3> {
+        let rotation = Rotation3{matrix: matrix};
+        let signed_permutation = (0 .. 3).all(|i| {
+            let row = matrix[i];
+            let column = [matrix[0][i], matrix[1][i], matrix[2][i]];
+            return [row, column].iter().all(|line| line.iter().map(|v| v.abs()).sum::<i32>() == 1);
+        });
+        if !signed_permutation || rotation.determinant() != 1 {
+            return None;
+        }
+        return Some(rotation);
+    }
+
+    pub fn matrix(&self) -> [[i32; 3]; 3] {
+        return self.matrix;
+    }
+
+    pub fn determinant(&self) -> i32 {
+        let m = &self.matrix;
+        return m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+             - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+             + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    }
+
+    pub fn apply(&self, pos: Pos) -> Pos {
+        let row = |r: &[i32; 3]| r[0] * pos.vec[0] + r[1] * pos.vec[1] + r[2] * pos.vec[2];
+        return Pos::new(row(&self.matrix[0]), row(&self.matrix[1]), row(&self.matrix[2]));
+    }
+
+    // The rotation by other first and then by self
+    pub fn compose(&self, other: &Rotation3) -> Rotation3 {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0 .. 3).map(|k| self.matrix[i][k] * other.matrix[k][j]).sum();
+            }
+        }
+        return Rotation3{matrix: matrix};
+    }
+
+    // The transpose, as for any rotation matrix
+    pub fn inverse(&self) -> Rotation3 {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in self.matrix.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                matrix[j][i] = *value;
+            }
+        }
+        return Rotation3{matrix: matrix};
+    }
+
+    //
+    // All 24 rotations, the identity first: every way to send the axes to
+    // the axes with signs whose determinant is 1
+    //
+    pub fn all() -> Vec<Rotation3> {
+        let mut rotations = Vec::with_capacity(24);
+        for permutation in PERMUTATIONS {
+            for signs in 0 .. 8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, column) in permutation.iter().enumerate() {
+                    matrix[row][*column] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                }
+                if let Some(rotation) = Rotation3::from_matrix(matrix) {
+                    rotations.push(rotation);
+                }
+            }
+        }
+        return rotations;
+    }
+}
+
+impl Default for Rotation3 {
+    fn default() -> Self {
+        return Rotation3::IDENTITY;
+    }
+}
+
+// The rows, e.g. "[1 0 0; 0 0 -1; 0 1 0]"
+impl fmt::Display for Rotation3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<String> = self.matrix.iter().map(|row| format!("{} {} {}", row[0], row[1], row[2])).collect();
+        return write!(f, "[{}]", rows.join("; "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn the_rotations_form_a_group() {
+        let all = Rotation3::all();
+        let set: HashSet<Rotation3> = all.iter().copied().collect();
+        assert_eq!((all.len(), set.len()), (24, 24));
+        assert_eq!(all[0], Rotation3::IDENTITY);
+
+        // Closed under composition and inverse
+        for a in &all {
+            assert!(set.contains(&a.inverse()));
+            assert_eq!(a.compose(&a.inverse()), Rotation3::IDENTITY);
+            for b in &all {
+                assert!(set.contains(&a.compose(b)), "{} after {}", a, b);
+            }
+        }
+
+        // Complete: the quarter turns generate nothing more and nothing less
+        let mut generated = HashSet::from([Rotation3::IDENTITY]);
+        let mut todo = vec![Rotation3::IDENTITY];
+        while let Some(rotation) = todo.pop() {
+            for quarter in [Rotation3::QUARTER_X, Rotation3::QUARTER_Y, Rotation3::QUARTER_Z] {
+                let next = quarter.compose(&rotation);
+                if generated.insert(next) {
+                    todo.push(next);
+                }
+            }
+        }
+        assert_eq!(generated, set);
+    }
+
+    #[test]
+    fn turns_positions() {
+        let pos = Pos::new(1, 2, 3);
+        assert_eq!(Rotation3::QUARTER_Z.apply(pos), Pos::new(-2, 1, 3));
+        assert_eq!(Rotation3::QUARTER_X.compose(&Rotation3::QUARTER_Z).apply(pos),
+                   Rotation3::QUARTER_X.apply(Rotation3::QUARTER_Z.apply(pos)));
+        let half = Rotation3::QUARTER_Y.compose(&Rotation3::QUARTER_Y);
+        assert_eq!(half.apply(pos), Pos::new(-1, 2, -3));
+        assert_eq!(Rotation3::QUARTER_Y.inverse(), half.compose(&Rotation3::QUARTER_Y));
+        // No two rotations send (1, 2, 3) to the same place
+        let images: HashSet<Pos> = Rotation3::all().iter().map(|rotation| rotation.apply(pos)).collect();
+        assert_eq!(images.len(), 24);
+
+        // A mirror image and a matrix that is not a signed permutation
+        assert_eq!(Rotation3::from_matrix([[-1, 0, 0], [0, 1, 0], [0, 0, 1]]), None);
+        assert_eq!(Rotation3::from_matrix([[1, 1, 0], [0, 1, 0], [0, 0, 1]]), None);
+        assert_eq!(Rotation3::from_matrix(Rotation3::QUARTER_X.matrix()), Some(Rotation3::QUARTER_X));
+    }
+}
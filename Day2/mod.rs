@@ -0,0 +1,70 @@
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day2;
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+struct NavPos {
+    x: i32,
+    y: i32,
+}
+
+struct SubmarineState {
+    pos: NavPos,
+    aim: i32,
+}
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+
+    fn parse(data: &str) -> Result<Vec<Command>, ParseError> {
+        let mut commands = Vec::new();
+        for elem in data.split("\n") {
+            let (operation, mag) = elem.split_once(' ')
+                                       .ok_or_else(|| ParseError::at(2, data, elem, "Expected \"<op> <magnitude>\""))?;
+            let mag = error::parse_number::<i32>(2, data, mag)?;
+
+            commands.push(match operation {
+                "forward" => Command::Forward(mag),
+                "down" => Command::Down(mag),
+                "up" => Command::Up(mag),
+                _ => return Err(ParseError::at(2, data, operation, "Unknown op")),
+            });
+        }
+        return Ok(commands);
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<Answer, NoAnswer> {
+        let mut pos = NavPos{x: 0, y: 0};
+        for command in commands {
+            match command {
+                Command::Forward(mag) => pos.x += mag,
+                Command::Down(mag) => pos.y += mag,
+                Command::Up(mag) => pos.y -= mag,
+            }
+        }
+        return Ok((pos.x * pos.y).into());
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<Answer, NoAnswer> {
+        let mut state = SubmarineState{
+            pos: NavPos{x: 0, y: 0},
+            aim: 0,
+        };
+        for command in commands {
+            match command {
+                Command::Forward(mag) => {state.pos.x += mag;
+                                          state.pos.y += state.aim * mag},
+                Command::Down(mag) => state.aim += mag,
+                Command::Up(mag) => state.aim -= mag,
+            }
+        }
+        return Ok((state.pos.x * state.pos.y).into());
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+use std::cmp;
+
+use std::collections::HashMap;
+
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day20;
+
+type Pos = (i64, i64);
+
+#[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)]
+pub enum Pixel {
+    Lit,
+    Dark,
+}
+
+impl Pixel {
+    fn from(c: char) -> Option<Pixel> {
+        return match c {
+            '#' => Some(Pixel::Lit),
+            '.' => Some(Pixel::Dark),
+            _ => None,
+        };
+    }
+
+    fn as_char(&self) -> char {
+        return match self {
+            Pixel::Lit => '#',
+            Pixel::Dark => ' ',
+        };
+    }
+}
+
+pub type ImageEnhancementAlgorithm = Vec<Pixel>;
+
+pub enum PixelCount {
+    Finite(u64),
+    Infinite,
+}
+
+impl fmt::Display for PixelCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PixelCount::Finite(v) => write!(f, "{}", v),
+            PixelCount::Infinite => write!(f, "Inf"),
+        }
+    }
+}
+
+
+#[derive(Clone)]
+pub struct Image {
+    pixels: HashMap<Pos, Pixel>,
+    outside_bounds: Pixel,
+}
+
+impl Image {
+    fn bounds(&self) -> (Pos, Pos) {
+        if self.pixels.is_empty() {
+            return ((0,0), (0,0));
+        }
+
+        let mut min = (i64::MAX, i64::MAX);
+        let mut max = (i64::MIN, i64::MIN);
+        for pos in self.pixels.keys() {
+            min.0 = cmp::min(min.0, pos.0);
+            min.1 = cmp::min(min.1, pos.1);
+            max.0 = cmp::max(max.0, pos.0);
+            max.1 = cmp::max(max.1, pos.1);
+        }
+        return (min, max);
+    }
+
+    fn get(&self, pos: Pos) -> Pixel {
+        return *self.pixels.get(&pos).unwrap_or(&self.outside_bounds);
+    }
+
+    pub fn render(&self) -> String {
+        let mut res = String::new();
+        let (min, max) = self.bounds();
+        for y in min.1 - 1 ..= max.1 + 1 {
+            for x in min.0 - 1 ..= max.0 + 1 {
+                res.push(self.get((x, y)).as_char());
+            }
+            res.push('\n');
+        }
+        return res;
+    }
+
+    pub fn count_lit(&self) -> PixelCount {
+        if self.outside_bounds == Pixel::Lit {
+            return PixelCount::Infinite;
+        }
+        return PixelCount::Finite(self.pixels.values().map(|p| if *p == Pixel::Lit {1} else {0}).sum());
+    }
+
+    pub fn enhance(&self, algo: &ImageEnhancementAlgorithm) -> Image {
+        let mut non_default_image: HashMap<Pos, Pixel> = HashMap::new();
+
+        let (min, max) = self.bounds();
+        for y in min.1 - 1 ..= max.1 + 1 {
+            for x in min.0 - 1 ..= max.0 + 1 {
+                let mut index = 0;
+                let mut left_shifts = 8;
+                for grid_y in y - 1 ..= y + 1 {
+                    for grid_x in x - 1 ..= x + 1 {
+                        if self.get((grid_x, grid_y)) == Pixel::Lit {
+                            index |= 1 << left_shifts;
+                        }
+                        left_shifts -= 1;
+                    }
+                }
+                non_default_image.insert((x, y), algo[index]);
+            }
+        }
+
+        let new_outside_bounds = match self.outside_bounds {
+            Pixel::Lit => algo[0x1FF],
+            Pixel::Dark => algo[0],
+        };
+
+        return Image{pixels: non_default_image, outside_bounds: new_outside_bounds};
+    }
+}
+
+fn enhance_times(algo: &ImageEnhancementAlgorithm, image: &Image, steps: u32) -> Answer {
+    let mut image = image.clone();
+    for _step in 0 .. steps {
+        image = image.enhance(algo);
+    }
+    return match image.count_lit() {
+        PixelCount::Finite(v) => v.into(),
+        PixelCount::Infinite => image.count_lit().to_string().into(),
+    };
+}
+
+fn parse_pixel(data: &str, line: &str, index: usize, c: char) -> Result<Pixel, ParseError> {
+    return Pixel::from(c).ok_or_else(|| ParseError::at(20, data, error::char_at(line, index), "Expected '#' or '.'"));
+}
+
+impl Solution for Day20 {
+    type Input = (ImageEnhancementAlgorithm, Image);
+
+    fn parse(data: &str) -> Result<(ImageEnhancementAlgorithm, Image), ParseError> {
+        let mut parsing_algo = true;
+
+        let mut algo = ImageEnhancementAlgorithm::new();
+
+        let mut non_default_image: HashMap<Pos, Pixel> = HashMap::new();
+        let mut image_y = 0;
+        for line in data.lines() {
+            if line.is_empty() {
+                if parsing_algo && algo.len() != 512 {
+                    return Err(ParseError::at(20, data, line, "Expected 512 pixels in the algorithm"));
+                }
+                parsing_algo = false;
+            } else if parsing_algo {
+                for (i, c) in line.char_indices() {
+                    algo.push(parse_pixel(data, line, i, c)?);
+                }
+            } else {
+                for (x, (i, c)) in line.char_indices().enumerate() {
+                    non_default_image.insert((x as i64, image_y), parse_pixel(data, line, i, c)?);
+                }
+                image_y += 1;
+            }
+        }
+        return Ok((algo, Image{pixels: non_default_image, outside_bounds: Pixel::Dark}));
+    }
+
+    fn part1((algo, image): &(ImageEnhancementAlgorithm, Image)) -> Result<Answer, NoAnswer> {
+        return Ok(enhance_times(algo, image, 2));
+    }
+
+    fn part2((algo, image): &(ImageEnhancementAlgorithm, Image)) -> Result<Answer, NoAnswer> {
+        return Ok(enhance_times(algo, image, 50));
+    }
+}
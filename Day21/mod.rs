@@ -0,0 +1,156 @@
+use std::cmp;
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day21;
+
+#[derive(Debug)] #[derive(PartialEq)] #[derive(Eq)] #[derive(Hash)] #[derive(Clone)]
+pub struct PlayerState {
+    pub id: u8,
+    pub pos: u8,
+    pub score: u64,
+}
+
+impl PlayerState {
+    fn do_turn(&self) -> Vec<PlayerState> {
+        let mut new_universes: Vec<PlayerState> = Vec::new();
+        for d1 in [1, 2, 3] {
+            for d2 in [1, 2, 3] {
+                for d3 in [1, 2, 3] {
+                    let steps = d1 + d2 + d3;
+                    let new_pos = ((self.pos as u32 + steps) % 10) as u8;
+                    let new_score = self.score + new_pos as u64 + 1;
+
+                    new_universes.push(PlayerState{id: self.id, pos: new_pos, score: new_score});
+                }
+            }
+        }
+        return new_universes;
+    }
+}
+
+// A player in the practice game with the deterministic die
+#[derive(Debug)]
+struct PracticePlayerState {
+    id: u8,
+    pos: u8,
+    next_dice_start: u8,
+    score: u64,
+}
+
+impl PracticePlayerState {
+    fn do_turn(&self, player_count: usize) -> PracticePlayerState {
+        let steps = (self.next_dice_start as u32 * 3) + 6;
+        let new_pos = ((self.pos as u32 + steps) % 10) as u8;
+        let new_score = self.score + new_pos as u64 + 1;
+        let new_next_dice_start = (self.next_dice_start + player_count as u8 * 3) % 100;
+
+        return PracticePlayerState{id: self.id, pos: new_pos, next_dice_start: new_next_dice_start, score: new_score};
+    }
+}
+
+#[derive(PartialEq)] #[derive(Eq)] #[derive(Hash)] #[derive(Clone)]
+struct GameState {
+    current_player_index: usize,
+    players: Vec<PlayerState>,
+}
+
+type Wins = i64;
+type WinCounts = HashMap<u8, Wins>;
+type Cache = HashMap<GameState, WinCounts>;
+
+
+fn play(players: &[PlayerState]) -> WinCounts
+{
+    fn _play_rec(state: GameState, cache: &mut Cache) -> WinCounts {
+        if let Some(cache_entry) = cache.get(&state) {
+            return cache_entry.clone();
+        }
+
+        let mut wins = WinCounts::new();
+        let cur_turn_index = state.current_player_index;
+        let next_turn = (cur_turn_index+ 1) % state.players.len();
+        let new_player_states = state.players[cur_turn_index].do_turn();
+        for player_state in new_player_states {
+            let mut new_game_state = state.clone();
+            new_game_state.players[cur_turn_index] = player_state.clone();
+            new_game_state.current_player_index = next_turn;
+            if player_state.score >= 21 {
+                *wins.entry(player_state.id).or_insert(0) += 1;
+            } else {
+                for (id, win_counts) in _play_rec(new_game_state, cache) {
+                    *wins.entry(id).or_insert(0) += win_counts;
+                }
+            }
+
+        }
+
+        cache.insert(state, wins.clone());
+        return wins;
+    }
+
+    let mut cache = Cache::new();
+    let state = GameState{current_player_index: 0, players: players.to_vec()};
+
+    return _play_rec(state, &mut cache);
+}
+
+impl Solution for Day21 {
+    type Input = Vec<PlayerState>;
+
+    fn parse(data: &str) -> Result<Vec<PlayerState>, ParseError> {
+        let start_pos_re = Regex::new(r"^Player (\d+) starting position: (\d+)$").unwrap();
+
+        let mut players: Vec<PlayerState> = Vec::new();
+        for line in data.lines() {
+            let cap = start_pos_re.captures(line)
+                                  .ok_or_else(|| ParseError::at(21, data, line, "Expected \"Player <id> starting position: <pos>\""))?;
+            let id = error::parse_number(21, data, cap.get(1).unwrap().as_str())?;
+            let pos_token = cap.get(2).unwrap().as_str();
+            let pos = error::parse_number::<u8>(21, data, pos_token)?;
+            if !(1 ..= 10).contains(&pos) {
+                return Err(ParseError::at(21, data, pos_token, "Position must be within 1..=10"));
+            }
+            players.push(PlayerState{id: id, pos: pos - 1, score: 0});
+        }
+        if players.is_empty() {
+            return Err(ParseError::at_end(21, data, "No players"));
+        }
+        return Ok(players);
+    }
+
+    fn part1(players: &Vec<PlayerState>) -> Result<Answer, NoAnswer> {
+        let mut states: Vec<PracticePlayerState> = players.iter().enumerate().map(|(index, p)|
+            PracticePlayerState{id: p.id, pos: p.pos, next_dice_start: (index * 3) as u8, score: 0}
+        ).collect();
+
+        let mut index = 0;
+        let mut rolls = 0;
+        loop {
+            let real_index = index % states.len();
+            states[real_index] = states[real_index].do_turn(states.len());
+            rolls += 3;
+            if states[real_index].score >= 1000 {
+                break;
+            }
+            index += 1;
+        }
+
+        let loser = states.iter().find(|player| player.score < 1000).unwrap();
+        return Ok((loser.score * rolls).into());
+    }
+
+    fn part2(players: &Vec<PlayerState>) -> Result<Answer, NoAnswer> {
+        let wins = play(players);
+
+        let mut highest = 0;
+        for win_count in wins.values() {
+            highest = cmp::max(highest, *win_count);
+        }
+        return Ok(highest.into());
+    }
+}
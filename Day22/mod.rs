@@ -1,32 +1,28 @@
-use std::fs;
-
 use regex::Regex;
 
-fn read_input() -> String {
-    let inputs =  fs::read_to_string("Day22/input").unwrap()
-                       .replace("\r", "");
-    
-    return inputs;
-}
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day22;
 
 #[derive(Clone)] #[derive(Copy)] #[derive(Debug)]
-struct Cube {
+pub struct Cube {
     start: (i64, i64, i64),
     end: (i64, i64, i64),
 }
 
 impl Cube {
-    fn new(start: (i64, i64, i64), end: (i64, i64, i64)) -> Self {
+    pub fn new(start: (i64, i64, i64), end: (i64, i64, i64)) -> Self {
         return Cube{start: start, end: end};
     }
 
     fn remove(&self, other: &Cube) -> Vec<Cube> {
-        let mut targets: Vec<Cube> = [self.clone()].to_vec();
+        let mut targets: Vec<Cube> = [*self].to_vec();
         loop {
             let mut any_intersection = false;
             for i in 0 .. targets.len() {
                 let target = targets[i];
-                if (other.end.0 > target.start.0 && other.start.0 < target.end.0) 
+                if (other.end.0 > target.start.0 && other.start.0 < target.end.0)
                     && (other.end.1 > target.start.1 && other.start.1 < target.end.1)
                     && (other.end.2 > target.start.2 && other.start.2 < target.end.2) {
                         any_intersection = true;
@@ -83,23 +79,41 @@ impl Cube {
         return targets;
     }
 
-    fn volume(&self) -> i64 {
-        return (self.end.0 - self.start.0).abs() * (self.end.1 - self.start.1).abs() * (self.end.2 - self.start.2).abs();  
+    pub fn volume(&self) -> i64 {
+        return (self.end.0 - self.start.0).abs() * (self.end.1 - self.start.1).abs() * (self.end.2 - self.start.2).abs();
     }
 
 }
 
 #[derive(Debug)]
-struct CubeSapce {
+pub struct RebootStep {
+    pub on: bool,
+    pub cube: Cube,
+}
+
+#[derive(Debug)] #[derive(Default)]
+pub struct CubeSapce {
     cubes: Vec<Cube>,
 }
 
 impl CubeSapce {
-    fn new() -> Self{
+    pub fn new() -> Self{
         return CubeSapce{cubes: Vec::new()};
     }
 
-    fn volume(&self) -> i64 {
+    pub fn reboot(steps: &[RebootStep]) -> Self {
+        let mut cube_space = CubeSapce::new();
+        for step in steps {
+            if step.on {
+                cube_space = cube_space.add(step.cube);
+            } else {
+                cube_space = cube_space.remove(step.cube);
+            }
+        }
+        return cube_space;
+    }
+
+    pub fn volume(&self) -> i64 {
         let mut volume: i64 = 0;
         for cube in &self.cubes {
             volume += cube.volume();
@@ -107,7 +121,7 @@ impl CubeSapce {
         return volume;
     }
 
-    fn add(&self, cube: Cube) -> CubeSapce{
+    pub fn add(&self, cube: Cube) -> CubeSapce{
         let mut new_space = CubeSapce::new();
         for old_target in &self.cubes {
             for new_cube in old_target.remove(&cube) {
@@ -117,7 +131,7 @@ impl CubeSapce {
         new_space.cubes.push(cube);
         return new_space;
     }
-    fn remove(&self, cube: Cube) -> CubeSapce{
+    pub fn remove(&self, cube: Cube) -> CubeSapce{
         let mut new_space = CubeSapce::new();
         for old_target in &self.cubes {
             for new_cube in old_target.remove(&cube) {
@@ -127,51 +141,45 @@ impl CubeSapce {
         return new_space;
     }
 
-    fn intersection_volume(&self, intersector: &Cube) -> i64 {
+    pub fn intersection_volume(&self, intersector: &Cube) -> i64 {
         let high_volume = self.volume();
-        let low_volume = self.remove(intersector.clone()).volume();
+        let low_volume = self.remove(*intersector).volume();
         return high_volume - low_volume;
     }
 }
 
-
-
-fn parse(data: &String) -> CubeSapce {
-    let reboot_step_re = Regex::new(
-        r"^((?:on)|(?:off)) x=([-\d]+)..([-\d]+),y=([-\d]+)..([-\d]+),z=([-\d]+)..([-\d]+)$").unwrap();
-
-    let mut cube_space = CubeSapce::new();
-    for line in data.lines() {
-        for cap in reboot_step_re.captures_iter(line) {
-            let on = match cap.get(1).unwrap().as_str() {
-                "on" => true,
-                "off" => false,
-                _ => panic!(),
-            };
-            let start = (cap.get(2).unwrap().as_str().parse::<i64>().unwrap(),
-                         cap.get(4).unwrap().as_str().parse::<i64>().unwrap(),
-                         cap.get(6).unwrap().as_str().parse::<i64>().unwrap());
-            let end =   (cap.get(3).unwrap().as_str().parse::<i64>().unwrap() + 1,
-                         cap.get(5).unwrap().as_str().parse::<i64>().unwrap() + 1,
-                         cap.get(7).unwrap().as_str().parse::<i64>().unwrap() + 1);
-            if on {
-                cube_space = cube_space.add(Cube::new(start, end));
-            } else {
-                cube_space = cube_space.remove(Cube::new(start, end));
+impl Solution for Day22 {
+    type Input = Vec<RebootStep>;
+
+    fn parse(data: &str) -> Result<Vec<RebootStep>, ParseError> {
+        let reboot_step_re = Regex::new(
+            r"^((?:on)|(?:off)) x=([-\d]+)..([-\d]+),y=([-\d]+)..([-\d]+),z=([-\d]+)..([-\d]+)$").unwrap();
+
+        let mut steps = Vec::new();
+        for line in data.lines() {
+            let cap = reboot_step_re.captures(line)
+                                    .ok_or_else(|| ParseError::at(22, data, line, "Expected \"<on|off> x=<from>..<to>,y=<from>..<to>,z=<from>..<to>\""))?;
+            let on = &cap[1] == "on";
+            let coord = |i: usize| error::parse_number::<i64>(22, data, cap.get(i).unwrap().as_str());
+            let start = (coord(2)?, coord(4)?, coord(6)?);
+            let end =   (coord(3)? + 1, coord(5)? + 1, coord(7)? + 1);
+            if start.0 >= end.0 || start.1 >= end.1 || start.2 >= end.2 {
+                return Err(ParseError::at(22, data, line, "Ranges must not be reversed"));
             }
+            steps.push(RebootStep{on: on, cube: Cube::new(start, end)});
         }
+        return Ok(steps);
     }
-    return cube_space;
-}
 
+    fn part1(steps: &Vec<RebootStep>) -> Result<Answer, NoAnswer> {
+        let cube_space = CubeSapce::reboot(steps);
+        return Ok(cube_space.intersection_volume(&Cube::new(
+            (-50, -50, -50),
+            (50+1, 50+1, 50+1)
+        )).into());
+    }
 
-fn main() {
-    let inp = read_input();
-    let cube_space = parse(&inp);
-
-    println!("Intersection Volume: {}", cube_space.intersection_volume(&Cube::new(
-        (-50, -50, -50),
-        (50+1, 50+1, 50+1)
-    )));
-    println!("Total Volume: {}", cube_space.volume());
-}
\ No newline at end of file
+    fn part2(steps: &Vec<RebootStep>) -> Result<Answer, NoAnswer> {
+        return Ok(CubeSapce::reboot(steps).volume().into());
+    }
+}
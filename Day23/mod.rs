@@ -1,21 +1,13 @@
-use std::fs;
-use std::io;
-use std::cmp::Reverse;
-use std::cmp::Ordering;
-
 use std::hash::{Hash, Hasher};
-use std::collections::HashSet;
-use std::collections::BinaryHeap;
-
-fn read_input() -> String {
-    let inputs =  fs::read_to_string("Day23/input").unwrap()
-                       .replace("\r", "");
-    
-    return inputs;
-}
+
+use crate::error::{self, ParseError};
+use crate::search::{self, Path, Problem};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day23;
 
 #[derive(Clone)] #[derive(PartialEq)]  #[derive(Eq)] #[derive(Debug)] #[derive(Copy)] #[derive(Hash)]
-enum AmphipodType {
+pub enum AmphipodType {
     Amber,
     Bronze,
     Copper,
@@ -64,10 +56,10 @@ enum Property {
 
 type Slot = Option<Amphipod>;
 
-#[derive(Clone)] #[derive(Debug)] #[derive(Eq)] #[derive(PartialEq)] 
+#[derive(Clone)] #[derive(Debug)] #[derive(Eq)] #[derive(PartialEq)]
 struct Connection {
     target: usize,
-    steps: i64, 
+    steps: i64,
 }
 
 impl Connection {
@@ -76,12 +68,12 @@ impl Connection {
     }
 }
 
-#[derive(Clone)] #[derive(Debug)] #[derive(Eq)] #[derive(PartialEq)] 
+#[derive(Clone)] #[derive(Debug)] #[derive(Eq)] #[derive(PartialEq)]
 struct Room {
     name: &'static str,
     property: Property,
     slots: Vec<Slot>,
-    connections: Vec<Connection>,    
+    connections: Vec<Connection>,
 }
 
 impl Room {
@@ -92,12 +84,12 @@ impl Room {
         }
         return Room{name: name, property: property, slots: slots, connections: Vec::new()};
 
-    }    
+    }
 
     fn remove(&mut self, amphi_id: usize)
     {
         for slot in &mut self.slots{
-            if slot.is_some() && slot.unwrap().id == amphi_id {
+            if slot.is_some_and(|a| a.id == amphi_id) {
                 *slot = None;
                 return;
             }
@@ -105,8 +97,8 @@ impl Room {
     }
 }
 
-#[derive(Clone)] #[derive(Eq)] #[derive(PartialEq)] 
-struct Map {
+#[derive(Clone)] #[derive(Eq)] #[derive(PartialEq)]
+pub struct Map {
     rooms: Vec<Room>
 }
 
@@ -120,42 +112,39 @@ impl Hash for Map {
     }
 }
 
+// Which types of amphipod sit where, the amphipods themselves are interchangeable
+type Positions = Vec<(usize, usize, Option<AmphipodType>)>;
+
 impl Map {
-    fn positions(&self) -> Vec<(usize, usize, Option<AmphipodType>)>
+    fn positions(&self) -> Positions
     {
         let mut results = Vec::new();
         for (room_i, room) in self.rooms.iter().enumerate() {
             for (slot_i, slot) in room.slots.iter().enumerate() {
-                let amphi_type;
-                if slot.is_some() {
-                    amphi_type = Some(slot.unwrap().atype);
-                } else {
-                    amphi_type = None;
-                }
-                results.push((room_i, slot_i, amphi_type));
+                results.push((room_i, slot_i, slot.map(|a| a.atype)));
             }
         }
         return results;
     }
 
-    fn new() -> Self {
+    fn new(room_depth: usize) -> Self {
         let mut rooms = Vec::new();
 
         rooms.push(Room::new("left", Property::Wait, 2));
         let left = rooms.len() - 1;
-        rooms.push(Room::new("amber", Property::Destination(AmphipodType::Amber), 2));
+        rooms.push(Room::new("amber", Property::Destination(AmphipodType::Amber), room_depth));
         let amber = rooms.len() - 1;
         rooms.push(Room::new("ab", Property::Corridor, 1));
         let ab = rooms.len() - 1;
-        rooms.push(Room::new("bronze", Property::Destination(AmphipodType::Bronze), 2));
+        rooms.push(Room::new("bronze", Property::Destination(AmphipodType::Bronze), room_depth));
         let bronze = rooms.len() - 1;
         rooms.push(Room::new("bc", Property::Corridor, 1));
         let bc = rooms.len() - 1;
-        rooms.push(Room::new("copper", Property::Destination(AmphipodType::Copper), 2));
+        rooms.push(Room::new("copper", Property::Destination(AmphipodType::Copper), room_depth));
         let copper = rooms.len() - 1;
         rooms.push(Room::new("cd", Property::Corridor, 1));
         let cd = rooms.len() - 1;
-        rooms.push(Room::new("desert", Property::Destination(AmphipodType::Desert), 2));
+        rooms.push(Room::new("desert", Property::Destination(AmphipodType::Desert), room_depth));
         let desert = rooms.len() - 1;
         rooms.push(Room::new("right", Property::Wait, 2));
         let right = rooms.len() - 1;
@@ -196,7 +185,15 @@ impl Map {
         return Map{rooms: rooms};
     }
 
-    fn render(&self) {
+    pub fn render(&self) -> String {
+        fn slot_char(slot: &Slot) -> char {
+            return match slot {
+                Some(amphi) => amphi.atype.representation(),
+                None => '.',
+            };
+        }
+
+        let mut res = String::new();
         let mut row = 0;
         let mut all_done = false;
         while !all_done {
@@ -205,18 +202,11 @@ impl Map {
                 match room.property {
                     Property::Destination(_) => {
                         if row == 0 {
-                            print!(".");
+                            res.push('.');
                         }
-                        else {
-                            if row - 1 < room.slots.len() {
-                                all_done = false;
-                                let slot = room.slots[row - 1];
-                                if slot.is_some() {
-                                    print!("{}", slot.unwrap().atype.representation());
-                                } else {
-                                    print!(".");
-                                }
-                            }
+                        else if row - 1 < room.slots.len() {
+                            all_done = false;
+                            res.push(slot_char(&room.slots[row - 1]));
                         }
                     }
                     Property::Wait | Property::Corridor => {
@@ -225,104 +215,67 @@ impl Map {
                             // This case is a bit ugly, but genrates much nicer representation
                             if room_i == 0 {
                                 for slot in room.slots.iter().rev() {
-                                    if slot.is_some() {
-                                        print!("{}", slot.unwrap().atype.representation());
-                                    } else {
-                                        print!(".");
-                                    }
+                                    res.push(slot_char(slot));
                                 }
                             } else {
                                 for slot in &room.slots {
-                                    if slot.is_some() {
-                                        print!("{}", slot.unwrap().atype.representation());
-                                    } else {
-                                        print!(".");
-                                    }
+                                    res.push(slot_char(slot));
                                 }
                             }
-                       
+
                         }
                         else {
                             for _slot in &room.slots {
-                                print!(" ");
+                                res.push(' ');
                             }
                         }
                     }
                 };
             }
             row += 1;
-            println!("");
+            res.push('\n');
         }
+        return res;
     }
 }
 
 
+// The burrow together with the energy used to get there
 #[derive(Clone)]
-struct ComplexState {
-    map: Map, 
-    cost: i64,  
-    heur_cost: i64,
-    prev: Option<Box<ComplexState>>,
-}
-
-impl Eq for ComplexState{
-
+pub struct ComplexState {
+    pub map: Map,
+    pub cost: i64,
 }
 
-impl PartialEq for ComplexState {
-    fn eq(&self, other: &Self) -> bool {
-        self.cost + self.heur_cost == other.cost + other.heur_cost
-    }
-}
-
-impl PartialOrd for ComplexState {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl Ord for ComplexState {
-    fn cmp(&self, other: &Self) -> Ordering {
-        (self.cost + self.heur_cost).cmp(&(other.cost + other.heur_cost))
+// One step per burrow, each with the energy used so far, followed by the total
+pub fn render(path: &Path<ComplexState>) -> String {
+    let mut res = String::new();
+    for (step, state) in path.states().enumerate() {
+        res += &format!("{:2}) -- Energy Used: {}\n", step + 1, state.cost);
+        res += &state.map.render();
     }
+    res += &format!("Tot. Cost: {}\n", path.cost);
+    return res;
 }
 
 impl ComplexState {
-    fn render(&self, step: i32) -> i32{
-        let step_shown;
-        if self.prev != None {
-            step_shown = self.prev.as_ref().unwrap().render(step + 1);
-        } else {
-            step_shown = 1;
-        }
-        println!("{:2}) -- Energy Used: {}", step_shown, self.cost);
-        self.map.render();
-        if step == 0 {
-            println!("Tot. Cost: {}", self.cost);
-        }
-        return step_shown + 1;
-    }
-
     fn heuristic(&self) -> i64{
         let mut heur_cost = 0;
-            
+
         for room in &self.map.rooms {
-            for slot in room.slots.iter() {
-                if slot.is_some() {
-                    heur_cost += match room.property {
-                        Property::Destination(dtype) => {
-                            let a = slot.unwrap(); 
-                            if a.atype != dtype {
-                                a.atype.step_cost() * 4
-                            } else {
-                                0
-                            }
-                        }
-                        Property::Corridor | Property::Wait => {
-                            let a = slot.unwrap(); 
-                            a.atype.step_cost() * 2
+            for a in room.slots.iter().flatten() {
+                heur_cost += match room.property {
+                    Property::Destination(dtype) => {
+                        if a.atype != dtype {
+                            a.atype.step_cost() * 4
+                        } else {
+                            0
                         }
-                    };
-                }
+                    }
+                    Property::Corridor | Property::Wait => {
+                        a.atype.step_cost() * 2
+                    }
+                };
             }
         }
         return heur_cost;
@@ -330,20 +283,13 @@ impl ComplexState {
 
     fn is_done(&self) -> bool {
         for room in &self.map.rooms {
-            match &room.property {
-                Property::Destination(atype) => {
-                    for slot in &room.slots {
-                        if slot.is_some() {
-                            if slot.unwrap().atype != *atype {
-                                return false;
-                            }
-                        } else {
-                            return false;
-                        }
+            if let Property::Destination(atype) = &room.property {
+                for slot in &room.slots {
+                    if !slot.is_some_and(|a| a.atype == *atype) {
+                        return false;
                     }
                 }
-                _ => {},
-            };
+            }
         }
         return true;
     }
@@ -351,11 +297,11 @@ impl ComplexState {
     fn next_states(&self) -> Vec<ComplexState> {
         let mut nexts: Vec<ComplexState> = Vec::new();
 
-        fn expand_from_room(room_i: usize, 
-                            parent: &ComplexState, 
-                            amphi: &Amphipod, 
-                            dest_only: bool, 
-                            extra_steps: i64, 
+        fn expand_from_room(room_i: usize,
+                            parent: &ComplexState,
+                            amphi: &Amphipod,
+                            dest_only: bool,
+                            extra_steps: i64,
                             prev_room_i: Option<usize>) -> Vec<(ComplexState, usize)> {
             let mut nexts: Vec<(ComplexState, usize)> = Vec::new();
             let room = &parent.map.rooms[room_i];
@@ -374,107 +320,124 @@ impl ComplexState {
                             }
                             let mut new_state = parent.clone();
                             new_state.map.rooms[room_i].remove(amphi.id);
-                            new_state.map.rooms[dest_i].slots[slot_i] = Some(amphi.clone());   
+                            new_state.map.rooms[dest_i].slots[slot_i] = Some(*amphi);
                             new_state.cost += (con.steps + extra_steps + slot_i as i64) * amphi.atype.step_cost();
-                            new_state.heur_cost = new_state.heuristic();
                             if dest_room.property == Property::Corridor {
-                                let mut subnexts = expand_from_room(dest_i, &new_state, &amphi, dest_only, slot_i as i64, Some(room_i));
+                                let mut subnexts = expand_from_room(dest_i, &new_state, amphi, dest_only, slot_i as i64, Some(room_i));
                                 nexts.append(&mut subnexts);
                             }
                             if !dest_only {
                                 nexts.push((new_state, dest_i));
-                            }                    
+                            }
                         }
                     }
                     Property::Destination(dest_atype) => {
                         if amphi.atype == dest_atype {
                             let mut deepest = None;
-                            for slot_i in 0 .. dest_room.slots.len() {
-                                let slot = &dest_room.slots[slot_i];
-                                if slot.is_some() {
-                                    if slot.unwrap().atype != dest_atype {
-                                        deepest = None;
-                                        break;
+                            for (slot_i, slot) in dest_room.slots.iter().enumerate() {
+                                match slot {
+                                    Some(other) => {
+                                        if other.atype != dest_atype {
+                                            deepest = None;
+                                            break;
+                                        }
                                     }
-                                } else {
-                                    deepest = Some(slot_i);
+                                    None => deepest = Some(slot_i),
                                 }
                             }
-                            if deepest.is_some() {
+                            if let Some(deepest) = deepest {
                                 let mut new_state = parent.clone();
                                 new_state.map.rooms[room_i].remove(amphi.id);
-                                new_state.map.rooms[dest_i].slots[deepest.unwrap()] = Some(amphi.clone());   
-                                new_state.cost += (con.steps + extra_steps + deepest.unwrap() as i64) * amphi.atype.step_cost();
-                                new_state.heur_cost = new_state.heuristic();
+                                new_state.map.rooms[dest_i].slots[deepest] = Some(*amphi);
+                                new_state.cost += (con.steps + extra_steps + deepest as i64) * amphi.atype.step_cost();
 
                                 nexts.push((new_state, dest_i));
-                                  
+
                             }
                         }
                     }
                 }
-            } 
-            return nexts;   
+            }
+            return nexts;
         }
 
-        
+
 
         for room_i in  0 .. self.map.rooms.len() {
             let room = &self.map.rooms[room_i];
             for slot_i in 0 .. room.slots.len() {
-                let slot = room.slots[slot_i];
-                if slot.is_some() {
-                    let amphi = &slot.unwrap();
+                if let Some(amphi) = &room.slots[slot_i] {
                     // Filter sources, creatures already in goal should never move
-                    match room.property {
-                        Property::Destination(dest_atype) => {
-                            if dest_atype == amphi.atype {
-                                if room.slots.iter().filter(|s| s.is_some()).all(|s| s.unwrap().atype == amphi.atype) {
-                                   break;
-                                }
-                            }
-                        }  
-                        _ => {}
-                    };
+                    if let Property::Destination(dest_atype) = room.property {
+                        if dest_atype == amphi.atype && room.slots.iter().flatten().all(|a| a.atype == amphi.atype) {
+                            break;
+                        }
+                    }
 
                     let dest_only = match room.property {
                         Property::Wait | Property::Corridor => true,
                         Property::Destination(_) => false,
                     };
-                    let mut new_nexts =  expand_from_room(room_i, &self, &amphi, dest_only, slot_i as i64, None);
+                    let mut new_nexts =  expand_from_room(room_i, self, amphi, dest_only, slot_i as i64, None);
 
                     // Can move directly to a room? Do so!
                     let mut had_one_dest = false;
-                    for (_sn, dest_i) in &new_nexts {    
-                        if match self.map.rooms[*dest_i].property { Property::Destination(_) => true, _=> false} {
+                    for (_sn, dest_i) in &new_nexts {
+                        if matches!(self.map.rooms[*dest_i].property, Property::Destination(_)) {
                             had_one_dest = true;
                             break;
                         }
                     }
                     for (sn, dest_i) in &mut new_nexts {
-                        if !had_one_dest || match self.map.rooms[*dest_i].property { Property::Destination(_) => true, _=> false} {
-                           //sn.prev = Some(Box::new(self.clone()));
+                        if !had_one_dest || matches!(self.map.rooms[*dest_i].property, Property::Destination(_)) {
                            nexts.push(sn.clone());
                         }
                     }
                     break;
                 }
             }
-        } 
+        }
 
         return nexts;
     }
-    
+
     fn new(map: Map) -> Self {
-        let mut state = ComplexState{map: map, cost: 0, heur_cost: 0, prev: None};
-        state.heur_cost = state.heuristic();
-        return state;
+        return ComplexState{map: map, cost: 0};
     }
 }
 
-fn parse(data: &String) -> ComplexState {
+// Moving the amphipods until each one sits in its destination room
+pub struct Organizer;
+
+impl Problem for Organizer {
+    type State = ComplexState;
+    type Key = Positions;
+
+    fn key(&self, state: &ComplexState) -> Positions {
+        return state.map.positions();
+    }
+
+    fn neighbours(&self, state: &ComplexState) -> Vec<(ComplexState, i64)> {
+        return state.next_states().into_iter().map(|next| {
+            let step_cost = next.cost - state.cost;
+            return (next, step_cost);
+        }).collect();
+    }
+
+    fn is_goal(&self, state: &ComplexState) -> bool {
+        return state.is_done();
+    }
 
-    let mut map = Map::new();
+    fn heuristic(&self, state: &ComplexState) -> i64 {
+        return state.heuristic();
+    }
+}
+
+// Rows of amphipods as listed in the burrow diagram, topmost first
+pub type Burrow = Vec<Vec<AmphipodType>>;
+
+fn build_state(burrow: &Burrow) -> ComplexState {
+    let mut map = Map::new(burrow.len());
     fn insert_into_room(name: &str, amphi: Amphipod, map: &mut Map) {
         for room in &mut map.rooms {
             if room.name == name {
@@ -490,9 +453,8 @@ fn parse(data: &String) -> ComplexState {
     }
 
     let mut id = 0;
-    for line in data.lines() {
-
-        for c in line.chars() {
+    for row in burrow {
+        for atype in row {
             let name = match id % 4{
                 0 => "amber",
                 1 => "bronze",
@@ -500,63 +462,66 @@ fn parse(data: &String) -> ComplexState {
                 3 => "desert",
                 _ => panic!(),
             };
-    
-            match c {
-                'A' => { 
-                    insert_into_room(name, Amphipod::new(id,  AmphipodType::Amber), &mut map);
-                    id += 1;
-                },
-                'B' => { 
-                    insert_into_room(name, Amphipod::new(id,  AmphipodType::Bronze), &mut map); 
-                    id += 1;
-                },
-                'C' => { 
-                    insert_into_room(name, Amphipod::new(id,  AmphipodType::Copper), &mut map); 
-                    id += 1;
-                },
-                'D' => { 
-                    insert_into_room(name, Amphipod::new(id,  AmphipodType::Desert), &mut map); 
-                    id += 1;
-                },
-                _ => {},
-            }
+            insert_into_room(name, Amphipod::new(id, *atype), &mut map);
+            id += 1;
         }
     }
     return ComplexState::new(map);
 }
 
+pub fn organize(complex_state: ComplexState) -> Option<Path<ComplexState>> {
+    return search::astar(&Organizer, complex_state).path;
+}
 
-fn main() {
-    let inp = read_input();
-    let complex_state = parse(&inp);
-
-    let mut pqueue: BinaryHeap<Reverse<ComplexState>> = BinaryHeap::new();
-    let mut seen = HashSet::new();
-    let mut best: Option<ComplexState> = None;
-
-    pqueue.push(Reverse(complex_state));
-    
-    while pqueue.len() > 0 {
-        let state = pqueue.pop().unwrap().0;
-        
-        let new_state_positions = state.map.positions();
-        if !seen.insert(new_state_positions){
-            continue;
-        }
+fn least_energy(burrow: &Burrow) -> Result<Answer, NoAnswer> {
+    let path = organize(build_state(burrow)).ok_or_else(|| NoAnswer::new(23, "The amphipods cannot be organized"))?;
+    return Ok(path.cost.into());
+}
 
-        if best.is_none() || best.as_ref().unwrap().heur_cost > state.heur_cost {
-            best = Some(state.clone());
-            state.render(0);
+impl Solution for Day23 {
+    type Input = Burrow;
+
+    fn parse(data: &str) -> Result<Burrow, ParseError> {
+        let mut burrow = Burrow::new();
+        for line in data.lines() {
+            let mut row: Vec<AmphipodType> = Vec::new();
+            for (i, c) in line.char_indices() {
+                row.push(match c {
+                    'A' => AmphipodType::Amber,
+                    'B' => AmphipodType::Bronze,
+                    'C' => AmphipodType::Copper,
+                    'D' => AmphipodType::Desert,
+                    '#' | '.' | ' ' => continue,
+                    _ => return Err(ParseError::at(23, data, error::char_at(line, i), "Unknown amphipod")),
+                });
+            }
+            if !row.is_empty() {
+                if row.len() != 4 {
+                    return Err(ParseError::at(23, data, line, "Expected an amphipod in each of the 4 rooms"));
+                }
+                burrow.push(row);
+            }
         }
-
-        if state.is_done() {
-            state.render(0);
-            println!("Least Energy Cost: {}", state.cost);
-            break;
+        if burrow.is_empty() {
+            return Err(ParseError::at_end(23, data, "No amphipods"));
         }
-        let new_states = state.next_states();
-        for new_state in new_states{
-            pqueue.push(Reverse(new_state));
+        for atype in [AmphipodType::Amber, AmphipodType::Bronze, AmphipodType::Copper, AmphipodType::Desert] {
+            if burrow.iter().flatten().filter(|a| **a == atype).count() != burrow.len() {
+                return Err(ParseError::at_end(23, data, &format!("Expected {} amphipods of each type", burrow.len())));
+            }
         }
+        return Ok(burrow);
     }
-}
\ No newline at end of file
+
+    fn part1(burrow: &Burrow) -> Result<Answer, NoAnswer> {
+        return least_energy(burrow);
+    }
+
+    fn part2(burrow: &Burrow) -> Result<Answer, NoAnswer> {
+        // The folded out part of the diagram
+        let mut unfolded = burrow.clone();
+        unfolded.insert(1, [AmphipodType::Desert, AmphipodType::Copper, AmphipodType::Bronze, AmphipodType::Amber].to_vec());
+        unfolded.insert(2, [AmphipodType::Desert, AmphipodType::Bronze, AmphipodType::Amber, AmphipodType::Copper].to_vec());
+        return least_energy(&unfolded);
+    }
+}
@@ -0,0 +1,125 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use crate::bench::Throughput;
+
+use super::optimize;
+use super::{Alu, AluDatum, AluError, AluInteger, Instruction, Operator, Program, Variable};
+
+pub type Registers = [AluInteger; 4];
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)]
+enum Operand {
+    Register(usize),
+    Literal(AluInteger),
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)]
+enum Op {
+    Inp(usize),
+    Apply(Operator, usize, Operand),
+}
+
+//
+// A program with its variables resolved to register indices up front, run on
+// a plain array of registers instead of the map of the Alu
+//
+#[derive(Debug)] #[derive(Clone)]
+pub struct CompiledProgram {
+    ops: Vec<Op>,
+}
+
+impl CompiledProgram {
+    pub fn new(program: &[Instruction]) -> Self {
+        fn operand(datum: &AluDatum) -> Operand {
+            return match datum {
+                AluDatum::Var(var) => Operand::Register(var.index()),
+                AluDatum::Literal(value) => Operand::Literal(*value),
+            };
+        }
+
+        let ops = program.iter().map(|instr| match instr {
+            Instruction::Inp(var) => Op::Inp(var.index()),
+            _ => {
+                let (var, operator, datum) = Operator::of(instr).unwrap();
+                Op::Apply(operator, var.index(), operand(&datum))
+            }
+        }).collect();
+        return CompiledProgram{ops: ops};
+    }
+
+    pub fn len(&self) -> usize {
+        return self.ops.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.ops.is_empty();
+    }
+
+    pub fn run(&self, inputs: &[AluInteger]) -> Result<Registers, AluError> {
+        return self.run_from([0; 4], inputs);
+    }
+
+    //
+    // Runs the program on the given registers. Instruction indices in errors
+    // count from the start of this program.
+    //
+    pub fn run_from(&self, mut registers: Registers, inputs: &[AluInteger]) -> Result<Registers, AluError> {
+        let mut inputs = inputs.iter();
+        for (index, op) in self.ops.iter().enumerate() {
+            match *op {
+                Op::Inp(r) => registers[r] = *inputs.next().ok_or(AluError::InputExhausted{index: index})?,
+                Op::Apply(operator, r, operand) => {
+                    let value = match operand {
+                        Operand::Register(i) => registers[i],
+                        Operand::Literal(value) => value,
+                    };
+                    registers[r] = operator.execute(registers[r], value, index)?;
+                }
+            }
+        }
+        return Ok(registers);
+    }
+}
+
+//
+// Runs count model numbers through the program on the Alu, compiled and
+// compiled after optimising. Every executor gets the same digits, the
+// first of them the program faults on ends the benchmark.
+//
+pub fn benchmark(program: &Program, count: usize) -> Result<Vec<Throughput>, AluError> {
+    fn digits(i: usize) -> Vec<AluInteger> {
+        // Spread the numbers over the whole range of 9^14 model numbers
+        let mut n = (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) % 22_876_792_454_961;
+        let mut digits = Vec::new();
+        for _digit in 0 .. 14 {
+            digits.push((n % 9) as AluInteger + 1);
+            n /= 9;
+        }
+        return digits;
+    }
+
+    fn measure<F>(name: &str, count: usize, mut run: F) -> Result<Throughput, AluError>
+        where F: FnMut(&[AluInteger]) -> Result<AluInteger, AluError> {
+        let inputs: Vec<Vec<AluInteger>> = (0 .. count).map(digits).collect();
+        let start = Instant::now();
+        for input in &inputs {
+            black_box(run(black_box(input))?);
+        }
+        return Ok(Throughput{name: name.to_string(), items: count, elapsed: start.elapsed()});
+    }
+
+    let compiled = CompiledProgram::new(program);
+    let optimized = CompiledProgram::new(&optimize::optimize(program, &[Variable::Z]));
+    let z = Variable::Z.index();
+
+    return Ok(vec![
+        measure("alu", count, |input| {
+            let mut alu = Alu::new();
+            alu.run(program, input.to_vec(), false)?;
+            return Ok(alu.variables[&Variable::Z]);
+        })?,
+        measure(&format!("compiled ({} instructions)", compiled.len()), count, |input| Ok(compiled.run(input)?[z]))?,
+        measure(&format!("optimized ({} instructions)", optimized.len()), count, |input| Ok(optimized.run(input)?[z]))?,
+    ]);
+}
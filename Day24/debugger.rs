@@ -0,0 +1,295 @@
+use std::fmt;
+
+use crate::json;
+
+use super::compiled::Registers;
+use super::{AluDatum, AluError, AluInteger, Instruction, Operator, Program, Variable, VARIABLES};
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Breakpoint {
+    // Before the instruction at the index, starting at 0
+    Instruction(usize),
+    // Before every inp
+    AnyInput,
+    // Before the inp reading the n-th input, starting at 1
+    Input(usize),
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Stop {
+    // A single instruction was executed
+    Step,
+    Breakpoint(Breakpoint),
+    Watch{var: Variable, before: AluInteger, after: AluInteger},
+    Finished,
+    // The instruction at the index of the error cannot be executed, it stays the next one
+    Fault(AluError),
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stop::Step => write!(f, "step"),
+            Stop::Breakpoint(Breakpoint::Instruction(index)) => write!(f, "breakpoint at instruction {}", index),
+            Stop::Breakpoint(Breakpoint::AnyInput) => write!(f, "breakpoint on input"),
+            Stop::Breakpoint(Breakpoint::Input(n)) => write!(f, "breakpoint on input {}", n),
+            Stop::Watch{var, before, after} => write!(f, "{} changed from {} to {}", var, before, after),
+            Stop::Finished => write!(f, "finished"),
+            Stop::Fault(error) => write!(f, "fault: {}", error),
+        }
+    }
+}
+
+// One executed instruction with the registers around it
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct TraceEntry {
+    pub index: usize,
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+}
+
+//
+// Runs a program an instruction at a time, stopping at breakpoints and when
+// watched registers change. Every executed instruction is recorded in the
+// trace.
+//
+pub struct Debugger<'a> {
+    program: &'a Program,
+    inputs: Vec<AluInteger>,
+    inputs_read: usize,
+    registers: Registers,
+    index: usize,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<Variable>,
+    trace: Vec<TraceEntry>,
+    // Where the last breakpoint stopped, so resuming does not stop there again
+    paused_at: Option<usize>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(program: &'a Program, inputs: &[AluInteger]) -> Self {
+        return Debugger{program: program, inputs: inputs.to_vec(), inputs_read: 0, registers: [0; 4], index: 0,
+                        breakpoints: Vec::new(), watches: Vec::new(), trace: Vec::new(), paused_at: None};
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        let count = self.breakpoints.len();
+        self.breakpoints.retain(|b| *b != breakpoint);
+        return self.breakpoints.len() != count;
+    }
+
+    pub fn watch(&mut self, var: Variable) {
+        if !self.watches.contains(&var) {
+            self.watches.push(var);
+        }
+    }
+
+    pub fn unwatch(&mut self, var: Variable) {
+        self.watches.retain(|v| *v != var);
+    }
+
+    pub fn registers(&self) -> Registers {
+        return self.registers;
+    }
+
+    pub fn register(&self, var: Variable) -> AluInteger {
+        return self.registers[var.index()];
+    }
+
+    // Index of the instruction executed next
+    pub fn position(&self) -> usize {
+        return self.index;
+    }
+
+    pub fn next_instruction(&self) -> Option<Instruction> {
+        return self.program.get(self.index).copied();
+    }
+
+    pub fn inputs_read(&self) -> usize {
+        return self.inputs_read;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        return self.index >= self.program.len();
+    }
+
+    pub fn trace(&self) -> &[TraceEntry] {
+        return &self.trace;
+    }
+
+    // Executes the next instruction
+    pub fn step(&mut self) -> Stop {
+        let instruction = match self.next_instruction() {
+            Some(instruction) => instruction,
+            None => return Stop::Finished,
+        };
+        let before = self.registers;
+        if let Err(error) = self.execute(instruction) {
+            return Stop::Fault(error);
+        }
+        self.trace.push(TraceEntry{index: self.index, instruction: instruction, before: before, after: self.registers});
+        self.index += 1;
+
+        for var in &self.watches {
+            let (before, after) = (before[var.index()], self.registers[var.index()]);
+            if before != after {
+                return Stop::Watch{var: *var, before: before, after: after};
+            }
+        }
+        return if self.is_finished() { Stop::Finished } else { Stop::Step };
+    }
+
+    //
+    // Runs until a breakpoint is reached, a watched register changes or the
+    // program ends. Resuming from a breakpoint moves past it.
+    //
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            if self.paused_at != Some(self.index) {
+                if let Some(breakpoint) = self.breakpoint_reached() {
+                    self.paused_at = Some(self.index);
+                    return Stop::Breakpoint(breakpoint);
+                }
+            }
+            self.paused_at = None;
+
+            let stop = self.step();
+            if stop != Stop::Step {
+                return stop;
+            }
+        }
+    }
+
+    fn breakpoint_reached(&self) -> Option<Breakpoint> {
+        let reads_input = matches!(self.next_instruction(), Some(Instruction::Inp(_)));
+        return self.breakpoints.iter().copied().find(|breakpoint| match breakpoint {
+            Breakpoint::Instruction(index) => *index == self.index,
+            Breakpoint::AnyInput => reads_input,
+            Breakpoint::Input(n) => reads_input && *n == self.inputs_read + 1,
+        });
+    }
+
+    fn execute(&mut self, instruction: Instruction) -> Result<(), AluError> {
+        if let Instruction::Inp(var) = instruction {
+            let value = *self.inputs.get(self.inputs_read).ok_or(AluError::InputExhausted{index: self.index})?;
+            self.registers[var.index()] = value;
+            self.inputs_read += 1;
+            return Ok(());
+        }
+
+        let (var, operator, datum) = Operator::of(&instruction).unwrap();
+        let (a, b) = (self.registers[var.index()], match datum {
+            AluDatum::Var(source) => self.registers[source.index()],
+            AluDatum::Literal(value) => value,
+        });
+        self.registers[var.index()] = operator.execute(a, b, self.index)?;
+        return Ok(());
+    }
+}
+
+// Columns of the exported traces, the registers before and after each instruction
+fn trace_columns() -> Vec<String> {
+    let mut columns = vec!["index".to_string(), "instruction".to_string()];
+    for when in ["before", "after"] {
+        columns.extend(VARIABLES.iter().map(|var| format!("{}_{}", var, when)));
+    }
+    return columns;
+}
+
+fn trace_row(entry: &TraceEntry) -> Vec<String> {
+    let mut row = vec![entry.index.to_string(), entry.instruction.to_string()];
+    row.extend(entry.before.iter().chain(entry.after.iter()).map(|value| value.to_string()));
+    return row;
+}
+
+pub fn trace_to_csv(trace: &[TraceEntry]) -> String {
+    let mut res = trace_columns().join(",");
+    res.push('\n');
+    for entry in trace {
+        res.push_str(&trace_row(entry).join(","));
+        res.push('\n');
+    }
+    return res;
+}
+
+pub fn trace_to_json(trace: &[TraceEntry]) -> String {
+    let columns = trace_columns();
+    let records: Vec<String> = trace.iter().map(|entry| {
+        let fields: Vec<String> = columns.iter().zip(trace_row(entry)).enumerate().map(|(i, (name, value))| {
+            // Only the instruction is text
+            let value = if i == 1 { json::string(&value) } else { value };
+            return format!("\"{}\": {}", name, value);
+        }).collect();
+        return format!("  {{{}}}", fields.join(", "));
+    }).collect();
+    return format!("[\n{}\n]\n", records.join(",\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day24::Day24;
+    use crate::solution::Solution;
+
+    fn program() -> Program {
+        return Day24::parse("inp w\nadd z w\nmul z 3\ninp x\nadd z x\ndiv z x").unwrap();
+    }
+
+    #[test]
+    fn stops_at_breakpoints_and_watches() {
+        let program = program();
+        let mut debugger = Debugger::new(&program, &[2, 5]);
+        debugger.add_breakpoint(Breakpoint::Input(2));
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Input(2)));
+        assert_eq!((debugger.position(), debugger.register(Variable::Z)), (3, 6));
+
+        debugger.watch(Variable::Z);
+        assert_eq!(debugger.step(), Stop::Step);
+        debugger.add_breakpoint(Breakpoint::Instruction(5));
+        assert_eq!(debugger.resume(), Stop::Watch{var: Variable::Z, before: 6, after: 11});
+        // Stopping for the watch right before the breakpoint does not skip it
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Instruction(5)));
+        assert_eq!(debugger.resume(), Stop::Watch{var: Variable::Z, before: 11, after: 2});
+        assert!(debugger.is_finished());
+        assert_eq!(debugger.step(), Stop::Finished);
+        assert_eq!(debugger.trace().len(), 6);
+    }
+
+    #[test]
+    fn faults_keep_the_instruction() {
+        let program = program();
+        let mut debugger = Debugger::new(&program, &[2, 0]);
+        debugger.add_breakpoint(Breakpoint::AnyInput);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::AnyInput));
+        assert_eq!(debugger.position(), 0);
+        debugger.remove_breakpoint(Breakpoint::AnyInput);
+        debugger.add_breakpoint(Breakpoint::Instruction(2));
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Instruction(2)));
+        assert_eq!(debugger.resume(), Stop::Fault(AluError::DivideByZero{index: 5}));
+        assert_eq!(debugger.position(), 5);
+
+        let mut debugger = Debugger::new(&program, &[1]);
+        assert_eq!(debugger.resume(), Stop::Fault(AluError::InputExhausted{index: 3}));
+    }
+
+    #[test]
+    fn exports_traces() {
+        let program = program();
+        let mut debugger = Debugger::new(&program, &[2, 5]);
+        debugger.step();
+        debugger.step();
+        assert_eq!(trace_to_csv(debugger.trace()), "index,instruction,x_before,y_before,z_before,w_before,x_after,y_after,z_after,w_after\n\
+                                                    0,inp w,0,0,0,0,0,0,0,2\n\
+                                                    1,add z w,0,0,0,2,0,0,2,2\n");
+        assert_eq!(trace_to_json(&debugger.trace()[.. 1]),
+                   "[\n  {\"index\": 0, \"instruction\": \"inp w\", \"x_before\": 0, \"y_before\": 0, \"z_before\": 0, \"w_before\": 0, \
+                    \"x_after\": 0, \"y_after\": 0, \"z_after\": 0, \"w_after\": 2}\n]\n");
+    }
+}
@@ -0,0 +1,656 @@
+use std::fmt;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub mod symbolic;
+pub mod optimize;
+pub mod compiled;
+pub mod debugger;
+pub mod validate;
+
+use compiled::{CompiledProgram, Registers};
+
+pub struct Day24;
+
+#[derive(Debug)] #[derive(Hash)] #[derive(PartialEq)] #[derive(Eq)] #[derive(Copy)] #[derive(Clone)]
+pub enum Variable {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+pub const VARIABLES: [Variable; 4] = [Variable::X, Variable::Y, Variable::Z, Variable::W];
+
+impl Variable {
+    // Position in VARIABLES, for keeping the registers in an array
+    pub fn index(&self) -> usize {
+        return match self {
+            Variable::X => 0,
+            Variable::Y => 1,
+            Variable::Z => 2,
+            Variable::W => 3,
+        };
+    }
+}
+
+impl fmt::Display for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Variable::X => "x",
+            Variable::Y => "y",
+            Variable::Z => "z",
+            Variable::W => "w",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+pub type AluInteger = i64;
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum AluDatum {
+    Var(Variable),
+    Literal(AluInteger),
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Instruction {
+    Inp(Variable),
+    Add(Variable, AluDatum),
+    Mul(Variable, AluDatum),
+    Div(Variable, AluDatum),
+    Mod(Variable, AluDatum),
+    Eql(Variable, AluDatum),
+}
+
+impl fmt::Display for AluDatum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AluDatum::Var(var) => write!(f, "{}", var),
+            AluDatum::Literal(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+// As written in the program source, e.g. "add x -4"
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Inp(var) => write!(f, "inp {}", var),
+            Instruction::Add(var, datum) => write!(f, "add {} {}", var, datum),
+            Instruction::Mul(var, datum) => write!(f, "mul {} {}", var, datum),
+            Instruction::Div(var, datum) => write!(f, "div {} {}", var, datum),
+            Instruction::Mod(var, datum) => write!(f, "mod {} {}", var, datum),
+            Instruction::Eql(var, datum) => write!(f, "eql {} {}", var, datum),
+        }
+    }
+}
+
+pub type Program = Vec<Instruction>;
+
+/// A state in which the ALU cannot go on, located by the index of the instruction
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum AluError {
+    DivideByZero{index: usize},
+    // Modulo of a negative value or by a modulus of 0 or less
+    InvalidModulo{index: usize, value: AluInteger, modulus: AluInteger},
+    // An inp with every input already read
+    InputExhausted{index: usize},
+    // The result does not fit in an AluInteger
+    Overflow{index: usize},
+}
+
+impl AluError {
+    pub fn index(&self) -> usize {
+        return match *self {
+            AluError::DivideByZero{index} => index,
+            AluError::InvalidModulo{index, ..} => index,
+            AluError::InputExhausted{index} => index,
+            AluError::Overflow{index} => index,
+        };
+    }
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AluError::DivideByZero{index} => write!(f, "Division by zero at instruction {}", index),
+            AluError::InvalidModulo{index, value, modulus} => write!(f, "Invalid modulo {} % {} at instruction {}", value, modulus, index),
+            AluError::InputExhausted{index} => write!(f, "No input left at instruction {}", index),
+            AluError::Overflow{index} => write!(f, "Overflow at instruction {}", index),
+        }
+    }
+}
+
+impl std::error::Error for AluError {}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Operator {
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+impl Operator {
+    // Splits an instruction other than inp into its operands and operator
+    pub fn of(instr: &Instruction) -> Option<(Variable, Operator, AluDatum)> {
+        return match *instr {
+            Instruction::Inp(_) => None,
+            Instruction::Add(var, datum) => Some((var, Operator::Add, datum)),
+            Instruction::Mul(var, datum) => Some((var, Operator::Mul, datum)),
+            Instruction::Div(var, datum) => Some((var, Operator::Div, datum)),
+            Instruction::Mod(var, datum) => Some((var, Operator::Mod, datum)),
+            Instruction::Eql(var, datum) => Some((var, Operator::Eql, datum)),
+        };
+    }
+
+    pub fn instruction(&self, var: Variable, datum: AluDatum) -> Instruction {
+        return match self {
+            Operator::Add => Instruction::Add(var, datum),
+            Operator::Mul => Instruction::Mul(var, datum),
+            Operator::Div => Instruction::Div(var, datum),
+            Operator::Mod => Instruction::Mod(var, datum),
+            Operator::Eql => Instruction::Eql(var, datum),
+        };
+    }
+
+    fn symbol(&self) -> &'static str {
+        return match self {
+            Operator::Add => "+",
+            Operator::Mul => "*",
+            Operator::Div => "/",
+            Operator::Mod => "%",
+            Operator::Eql => "==",
+        };
+    }
+
+    // None for operations the ALU cannot perform
+    pub fn apply(&self, a: AluInteger, b: AluInteger) -> Option<AluInteger> {
+        return self.execute(a, b, 0).ok();
+    }
+
+    // The result of the instruction at the index, or why the ALU cannot perform it
+    pub fn execute(&self, a: AluInteger, b: AluInteger, index: usize) -> Result<AluInteger, AluError> {
+        let result = match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Mul => a.checked_mul(b),
+            Operator::Div if b == 0 => return Err(AluError::DivideByZero{index: index}),
+            Operator::Div => a.checked_div(b),
+            Operator::Mod if a < 0 || b <= 0 => return Err(AluError::InvalidModulo{index: index, value: a, modulus: b}),
+            Operator::Mod => Some(a % b),
+            Operator::Eql => Some(if a == b { 1 } else { 0 }),
+        };
+        return result.ok_or(AluError::Overflow{index: index});
+    }
+}
+
+pub type Inspector = fn (Instruction, &Alu);
+
+pub struct Alu {
+    pub variables : HashMap<Variable, AluInteger>,
+    pub inspectors : Vec<Inspector>,
+}
+
+impl fmt::Debug for Alu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Alu")
+         .field("x: ", &self.variables.get(&Variable::X))
+         .field("y: ", &self.variables.get(&Variable::Y))
+         .field("z: ", &self.variables.get(&Variable::Z))
+         .field("w: ", &self.variables.get(&Variable::W))
+         .finish()
+    }
+}
+
+impl Default for Alu {
+    fn default() -> Self {
+        return Alu::new();
+    }
+}
+
+impl Alu {
+    pub fn new() -> Self {
+        let mut vars = HashMap::new();
+        vars.insert(Variable::X, 0);
+        vars.insert(Variable::Y, 0);
+        vars.insert(Variable::Z, 0);
+        vars.insert(Variable::W, 0);
+
+        return Alu{variables: vars, inspectors: Vec::new()};
+    }
+
+    pub fn run(&mut self, program: &Program, inputs: Vec<i64>, verbose: bool) -> Result<(), AluError> {
+        fn read(alu: &Alu, var: &Variable) -> AluInteger {
+            *alu.variables.get(var).unwrap()
+        }
+        fn value(alu: &Alu, datum: &AluDatum) -> AluInteger {
+            match datum {
+                AluDatum::Literal(val) => *val,
+                AluDatum::Var(var) => read(alu, var),
+            }
+        }
+
+
+        let mut input_index = 0;
+
+        for (index, instr) in program.iter().enumerate() {
+            for i in &self.inspectors {
+                i(*instr, self);
+            }
+            match instr {
+                Instruction::Inp(var) => {
+                    if input_index >= inputs.len() {
+                        return Err(AluError::InputExhausted{index: index});
+                    }
+                    self.variables.insert(*var, inputs[input_index]); input_index += 1;
+                    if verbose {
+                        eprintln!("Got Input: {} ({})", input_index, inputs[input_index-1]);
+                    }
+                },
+                _ => {
+                    let (var, operator, datum) = Operator::of(instr).unwrap();
+                    let result = operator.execute(read(self, &var), value(self, &datum), index)?;
+                    self.variables.insert(var, result);
+                },
+            };
+
+        }
+        return Ok(());
+    }
+}
+
+
+// Note: Symbols go from 1-9, not 0-8. 1 is treated as 0 internally
+pub type Base9Integer = i64;
+
+pub fn from_string(s: &str) -> Base9Integer {
+    let mut val: Base9Integer = 0;
+    for c in s.chars() {
+        let digit = (c as i64) - '1' as i64;
+        val *= 9;
+        val += digit;
+    }
+    return val;
+}
+
+pub fn to_string(v: Base9Integer) -> String {
+    let mut string = String::new();
+    let mut divisor = 1;
+    while divisor == 1 || divisor <= v {
+        let val = (v / divisor) % 9;
+        string.insert(0, (b'1' + val as u8) as char);
+        divisor *= 9;
+    }
+    return string;
+}
+
+pub fn to_digits(v: Base9Integer, pad_to: usize) -> Vec<i64> {
+    let mut digits = Vec::new();
+    let mut divisor = 1;
+    while divisor == 1 || divisor <= v {
+        let val = (v / divisor) % 9;
+        digits.insert(0, 1 + val);
+        divisor *= 9;
+    }
+    while digits.len() < pad_to {
+        digits.insert(0, 1);
+    }
+    return digits;
+}
+
+
+
+pub fn peeker(instr: Instruction, alu: &Alu) {
+    match instr {
+        Instruction::Add(Variable::Z, AluDatum::Var(var)) => {
+            eprintln!("z += {:?}", alu.variables.get(&var).unwrap());
+            //println!("Pushed = {:?}", alu.variables.get(&Variable::Z).unwrap() % 26);
+        },
+        Instruction::Add(Variable::X, AluDatum::Literal(val)) => {
+            eprintln!("Constant: {:?}", val);
+            //println!("Pushed = {:?}", alu.variables.get(&Variable::Z).unwrap() % 26);
+        },
+        Instruction::Mod(Variable::X, AluDatum::Literal(26)) => {
+            eprintln!("Peeked: {:?}", alu.variables.get(&Variable::X).unwrap() % 26);
+            //println!("Pushed = {:?}", alu.variables.get(&Variable::Z).unwrap() % 26);
+        },
+        Instruction::Div(Variable::Z, AluDatum::Literal(26)) => {
+            eprintln!("Pop!");
+            //println!("Pushed = {:?}", alu.variables.get(&Variable::Z).unwrap() % 26);
+        },
+        Instruction::Eql(Variable::X, AluDatum::Var(Variable::W)) => {
+            let x = alu.variables.get(&Variable::X).unwrap();
+            let w = alu.variables.get(&Variable::W).unwrap();
+            eprintln!("x {:?} == w {:?}", x, w);
+            eprintln!("Multipl: {}", if x == w {0} else {1} );
+        }
+        _ => {}
+    }
+}
+
+
+const MODEL_NUMBER_DIGITS: usize = 14;
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Preference {
+    Largest,
+    Smallest,
+}
+
+impl Preference {
+    // The order in which digits are tried
+    fn digits(&self) -> [i64; 9] {
+        return match self {
+            Preference::Largest => [9, 8, 7, 6, 5, 4, 3, 2, 1],
+            Preference::Smallest => [1, 2, 3, 4, 5, 6, 7, 8, 9],
+        };
+    }
+}
+
+//
+// A MONAD program is made of 14 blocks of the same 18 instructions, one per
+// digit, that only differ in three constants. Each block does
+//
+//   w = digit
+//   x = z % 26 + check
+//   z = z / divisor
+//   if x != w { z = z * 26 + w + offset }
+//
+// so z is a stack of base 26 digits. Blocks with divisor 1 have a check above
+// 9 and always push their digit plus offset, blocks with divisor 26 pop and
+// only refrain from pushing again if their digit equals the popped value plus
+// check. z ends up as 0 if every pop matches.
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct MonadBlock {
+    pub divisor: AluInteger,
+    pub check: AluInteger,
+    pub offset: AluInteger,
+}
+
+impl MonadBlock {
+    pub fn instructions(&self) -> Vec<Instruction> {
+        use AluDatum::{Literal, Var};
+        use Instruction::*;
+        use Variable::*;
+
+        return vec![
+            Inp(W), Mul(X, Literal(0)), Add(X, Var(Z)), Mod(X, Literal(26)), Div(Z, Literal(self.divisor)),
+            Add(X, Literal(self.check)), Eql(X, Var(W)), Eql(X, Literal(0)),
+            Mul(Y, Literal(0)), Add(Y, Literal(25)), Mul(Y, Var(X)), Add(Y, Literal(1)), Mul(Z, Var(Y)),
+            Mul(Y, Literal(0)), Add(Y, Var(W)), Add(Y, Literal(self.offset)), Mul(Y, Var(X)), Add(Z, Var(Y)),
+        ];
+    }
+}
+
+// The blocks of the program, None if it does not follow the MONAD structure
+pub fn monad_blocks(program: &Program) -> Option<Vec<MonadBlock>> {
+    let block_len = 18;
+    if program.len() != MODEL_NUMBER_DIGITS * block_len {
+        return None;
+    }
+
+    let mut blocks = Vec::new();
+    for chunk in program.chunks(block_len) {
+        let block = match (chunk[4], chunk[5], chunk[15]) {
+            (Instruction::Div(_, AluDatum::Literal(divisor)),
+             Instruction::Add(_, AluDatum::Literal(check)),
+             Instruction::Add(_, AluDatum::Literal(offset))) => MonadBlock{divisor: divisor, check: check, offset: offset},
+            _ => return None,
+        };
+        if block.instructions() != chunk {
+            return None;
+        }
+        blocks.push(block);
+    }
+    return Some(blocks);
+}
+
+//
+// Pairs every popping block with the block that pushed the value it pops,
+// their digits must differ by the pushed offset plus the check of the pop.
+// Each pair is then set to the preferred digits independently.
+//
+fn solve_monad_blocks(blocks: &[MonadBlock], preference: Preference) -> Option<Vec<i64>> {
+    let mut digits = vec![0; blocks.len()];
+    let mut stack: Vec<usize> = Vec::new();
+
+    for (i, block) in blocks.iter().enumerate() {
+        match block.divisor {
+            1 if block.check > 9 => stack.push(i),
+            26 => {
+                let pushed = stack.pop()?;
+                let difference = blocks[pushed].offset + block.check;
+                if difference.abs() > 8 {
+                    return None;
+                }
+                digits[pushed] = match preference {
+                    Preference::Largest => 9.min(9 - difference),
+                    Preference::Smallest => 1.max(1 - difference),
+                };
+                digits[i] = digits[pushed] + difference;
+            }
+            _ => return None,
+        }
+    }
+    if !stack.is_empty() {
+        return None;
+    }
+    return Some(digits);
+}
+
+//
+// Fallback for programs not following the MONAD structure: a depth first
+// search over the digits, splitting the program at every input and
+// remembering the registers from which no digits lead to an accepted number
+//
+fn search_model_number(program: &Program, preference: Preference) -> Option<Vec<i64>> {
+    fn search(blocks: &[CompiledProgram], registers: Registers, preference: Preference,
+              digits: &mut Vec<i64>, dead_ends: &mut HashSet<(usize, Registers)>) -> bool {
+        let block_i = digits.len();
+        if block_i == blocks.len() {
+            return registers[Variable::Z.index()] == 0;
+        }
+        if dead_ends.contains(&(block_i, registers)) {
+            return false;
+        }
+        for digit in preference.digits() {
+            digits.push(digit);
+            // Digits for which the ALU faults are rejected like any other
+            let accepted = match blocks[block_i].run_from(registers, &[digit]) {
+                Ok(next) => search(blocks, next, preference, digits, dead_ends),
+                Err(_) => false,
+            };
+            if accepted {
+                return true;
+            }
+            digits.pop();
+        }
+        dead_ends.insert((block_i, registers));
+        return false;
+    }
+
+    // Instructions before the first input run once up front
+    let first_input = program.iter().position(|instr| matches!(instr, Instruction::Inp(_))).unwrap_or(program.len());
+    let mut blocks = Vec::new();
+    let mut start = first_input;
+    for i in first_input + 1 ..= program.len() {
+        if i == program.len() || matches!(program[i], Instruction::Inp(_)) {
+            blocks.push(CompiledProgram::new(&program[start .. i]));
+            start = i;
+        }
+    }
+    if blocks.len() > MODEL_NUMBER_DIGITS {
+        return None;
+    }
+
+    let registers = CompiledProgram::new(&program[.. first_input]).run(&[]).ok()?;
+    let mut digits = Vec::new();
+    if !search(&blocks, registers, preference, &mut digits, &mut HashSet::new()) {
+        return None;
+    }
+    // Digits the program never reads can be anything
+    digits.resize(MODEL_NUMBER_DIGITS, preference.digits()[0]);
+    return Some(digits);
+}
+
+//
+// The largest or smallest 14 digit model number accepted by the program,
+// None if it accepts none
+//
+pub fn find_model_number(program: &Program, preference: Preference) -> Option<String> {
+    let digits = monad_blocks(program).and_then(|blocks| solve_monad_blocks(&blocks, preference))
+                                      .or_else(|| search_model_number(program, preference))?;
+    return Some(digits.iter().map(|d| d.to_string()).collect());
+}
+
+//
+// Runs a model number through the program, the number is accepted if z ends up as 0
+//
+fn check_model_number(program: &Program, number: &str) -> Result<Answer, NoAnswer> {
+    let mut alu = Alu::new();
+    alu.run(program, to_digits(from_string(number), MODEL_NUMBER_DIGITS), false)
+       .map_err(|e| NoAnswer::new(24, &format!("Model number {} faults: {}", number, e)))?;
+    if *alu.variables.get(&Variable::Z).unwrap() != 0 {
+        return Err(NoAnswer::new(24, &format!("Model number {} is rejected", number)));
+    }
+
+    return Ok(number.parse::<i64>().unwrap().into());
+}
+
+fn model_number(program: &Program, preference: Preference) -> Result<Answer, NoAnswer> {
+    let number = find_model_number(program, preference).ok_or_else(|| NoAnswer::new(24, "The program accepts no model number"))?;
+    return check_model_number(program, &number);
+}
+
+impl Solution for Day24 {
+    type Input = Program;
+
+    fn parse(data: &str) -> Result<Program, ParseError> {
+        fn as_var(data: &str, i: &str) -> Result<Variable, ParseError> {
+            match i {
+                "x" => Ok(Variable::X),
+                "y" => Ok(Variable::Y),
+                "z" => Ok(Variable::Z),
+                "w" => Ok(Variable::W),
+                _ => Err(ParseError::at(24, data, i, "Expected a variable")),
+            }
+        }
+
+        fn as_datum(data: &str, i: &str) -> Result<AluDatum, ParseError> {
+            if let Ok(var) = as_var(data, i) {
+                return Ok(AluDatum::Var(var));
+            }
+            if let Ok(parsed) = i.parse::<AluInteger>() {
+                return Ok(AluDatum::Literal(parsed));
+            }
+            return Err(ParseError::at(24, data, i, "Expected a variable or a number"));
+        }
+
+        let mut program = Program::new();
+
+        for line in data.lines() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.is_empty() {
+                continue;
+            }
+
+            let operands = match tokens[0] {
+                "inp" => 1,
+                "add" | "mul" | "div" | "mod" | "eql" => 2,
+                _ => return Err(ParseError::at(24, data, tokens[0], "Unknown instruction")),
+            };
+            if tokens.len() != operands + 1 {
+                return Err(ParseError::at(24, data, line, &format!("Expected {} operands", operands)));
+            }
+
+            let var = as_var(data, tokens[1])?;
+            program.push(match tokens[0] {
+                "inp" => Instruction::Inp(var),
+                "add" => Instruction::Add(var, as_datum(data, tokens[2])?),
+                "mul" => Instruction::Mul(var, as_datum(data, tokens[2])?),
+                "div" => Instruction::Div(var, as_datum(data, tokens[2])?),
+                "mod" => Instruction::Mod(var, as_datum(data, tokens[2])?),
+                _ => Instruction::Eql(var, as_datum(data, tokens[2])?),
+            });
+        }
+        return Ok(program);
+    }
+
+    fn part1(program: &Program) -> Result<Answer, NoAnswer> {
+        return model_number(program, Preference::Largest);
+    }
+
+    fn part2(program: &Program) -> Result<Answer, NoAnswer> {
+        return model_number(program, Preference::Smallest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base9conversion() {
+        assert_eq!(to_string(from_string("3214")), "3214");
+
+        assert_eq!(to_string(from_string("99999999999999")), "99999999999999");
+    }
+
+    #[test]
+    fn test_base9conversion_to_digits() {
+        assert_eq!(to_digits(from_string("3214"), 4), [3, 2, 1, 4]);
+    }
+
+    // Pairs of a push of digit + 4 followed by a pop checking digit - 6
+    fn paired_monad(pairs: usize) -> Program {
+        let mut program = Program::new();
+        for _pair in 0 .. pairs {
+            program.extend(MonadBlock{divisor: 1, check: 12, offset: 4}.instructions());
+            program.extend(MonadBlock{divisor: 26, check: -6, offset: 1}.instructions());
+        }
+        return program;
+    }
+
+    #[test]
+    fn solves_monad_structure() {
+        let program = paired_monad(7);
+        assert_eq!(monad_blocks(&program).unwrap()[1], MonadBlock{divisor: 26, check: -6, offset: 1});
+        assert_eq!(find_model_number(&program, Preference::Largest).unwrap(), "97979797979797");
+        assert_eq!(find_model_number(&program, Preference::Smallest).unwrap(), "31313131313131");
+    }
+
+    #[test]
+    fn searches_other_programs() {
+        // Too short for a MONAD, the digits it does not read are free
+        let program = paired_monad(2);
+        assert!(monad_blocks(&program).is_none());
+        assert_eq!(find_model_number(&program, Preference::Largest).unwrap(), "97979999999999");
+        assert_eq!(find_model_number(&program, Preference::Smallest).unwrap(), "31311111111111");
+
+        let program = Day24::parse("inp z\nadd z -4").unwrap();
+        assert!(monad_blocks(&program).is_none());
+        assert_eq!(find_model_number(&program, Preference::Largest).unwrap(), "49999999999999");
+    }
+
+    #[test]
+    fn reports_programs_without_answer() {
+        let program = Day24::parse("inp z\nadd z 10").unwrap();
+        assert_eq!(Day24::part1(&program).unwrap_err().to_string(), "Day 24 has no answer: The program accepts no model number");
+        let error = crate::solution::solve::<Day24>("inp w\nadd z 1", 2).unwrap_err();
+        assert_eq!(error.to_string(), "Day 24 part 2 has no answer: The program accepts no model number");
+    }
+
+    #[test]
+    fn benchmarks_stop_at_faults() {
+        let program = Day24::parse("inp w\ninp x\nadd x -5\ndiv w x").unwrap();
+        assert_eq!(compiled::benchmark(&program, 100).unwrap_err(), AluError::DivideByZero{index: 3});
+        assert_eq!(compiled::benchmark(&Day24::parse("inp w\nadd z w").unwrap(), 10).unwrap().len(), 3);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,124 @@
+use std::rc::Rc;
+
+use super::symbolic::Expr;
+use super::{AluDatum, Instruction, Operator, Program, Variable};
+
+//
+// An equivalent program for the given outputs with fewer instructions:
+// constants are propagated, instructions that leave their variable unchanged
+// dropped and those with a result known up front, such as comparisons decided
+// by the range of their operands, replaced by resets to the result. What is
+// left is stripped of instructions the outputs do not depend on. Every input
+// is still read, but a division by zero may be dropped with the instruction.
+//
+pub fn optimize(program: &Program, outputs: &[Variable]) -> Program {
+    return eliminate_dead_code(&propagate_constants(program), outputs);
+}
+
+pub fn propagate_constants(program: &Program) -> Program {
+    let mut registers = [Expr::constant(0), Expr::constant(0), Expr::constant(0), Expr::constant(0)];
+    let mut digit = 0;
+    let mut optimized = Program::new();
+
+    for instr in program {
+        if let Instruction::Inp(var) = instr {
+            digit += 1;
+            registers[var.index()] = Expr::digit(digit);
+            optimized.push(*instr);
+            continue;
+        }
+
+        let (var, operator, datum) = Operator::of(instr).unwrap();
+        let current = registers[var.index()].clone();
+        let operand = match datum {
+            AluDatum::Var(source) => registers[source.index()].clone(),
+            AluDatum::Literal(value) => Expr::constant(value),
+        };
+        let datum = operand.as_const().map(AluDatum::Literal).unwrap_or(datum);
+        let result = Expr::apply(operator, current.clone(), operand);
+
+        let unchanged = Rc::ptr_eq(&result, &current) || (result.as_const().is_some() && result.as_const() == current.as_const());
+        if !unchanged {
+            match (result.as_const(), current.as_const()) {
+                (Some(0), _) => optimized.push(Instruction::Mul(var, AluDatum::Literal(0))),
+                (Some(value), Some(before)) if value.checked_sub(before).is_some() => {
+                    optimized.push(Instruction::Add(var, AluDatum::Literal(value - before)));
+                }
+                (Some(value), _) => {
+                    optimized.push(Instruction::Mul(var, AluDatum::Literal(0)));
+                    optimized.push(Instruction::Add(var, AluDatum::Literal(value)));
+                }
+                (None, _) => optimized.push(operator.instruction(var, datum)),
+            }
+        }
+        registers[var.index()] = result;
+    }
+    return optimized;
+}
+
+//
+// Walks the program backwards keeping track of the variables whose value is
+// still needed, instructions writing any other variable are dropped
+//
+pub fn eliminate_dead_code(program: &Program, outputs: &[Variable]) -> Program {
+    let mut live = [false; 4];
+    for var in outputs {
+        live[var.index()] = true;
+    }
+
+    let mut kept = Program::new();
+    for instr in program.iter().rev() {
+        if let Instruction::Inp(var) = instr {
+            live[var.index()] = false;
+            kept.push(*instr);
+            continue;
+        }
+
+        let (var, operator, datum) = Operator::of(instr).unwrap();
+        if !live[var.index()] {
+            continue;
+        }
+        kept.push(*instr);
+        // Multiplying by 0 does not depend on the previous value
+        if operator == Operator::Mul && datum == AluDatum::Literal(0) {
+            live[var.index()] = false;
+        }
+        if let AluDatum::Var(source) = datum {
+            live[source.index()] = true;
+        }
+    }
+    kept.reverse();
+    return kept;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day24::compiled::CompiledProgram;
+    use crate::day24::{Day24, MonadBlock};
+    use crate::solution::Solution;
+
+    #[test]
+    fn resets_decided_comparisons() {
+        let program = Day24::parse("inp w\nmul x 0\nadd x 11\neql x w\neql x 0\nmul y 0\nadd y w\nmul y x\nadd z y").unwrap();
+        let optimized = optimize(&program, &[Variable::Z]);
+        assert_eq!(optimized, Day24::parse("inp w\nadd y w\nadd z y").unwrap());
+    }
+
+    #[test]
+    fn keeps_monad_results() {
+        let mut program = Program::new();
+        for (divisor, check, offset) in [(1, 11, 6), (1, 13, 2), (26, -4, 7), (1, 10, 1), (26, -10, 3), (26, 0, 5)] {
+            program.extend(MonadBlock{divisor: divisor, check: check, offset: offset}.instructions());
+        }
+        let optimized = optimize(&program, &[Variable::Z]);
+        // Pushing blocks shrink to a handful of instructions, pops depend on the digits
+        assert!(optimized.len() < program.len() * 2 / 3, "{} instructions left", optimized.len());
+
+        let (original, optimized) = (CompiledProgram::new(&program), CompiledProgram::new(&optimized));
+        for digits in [[1, 1, 1, 1, 1, 1], [9, 9, 9, 9, 9, 9], [3, 5, 3, 2, 1, 4], [7, 1, 9, 9, 1, 9]] {
+            let z = original.run(&digits).unwrap()[Variable::Z.index()];
+            assert_eq!(optimized.run(&digits).unwrap()[Variable::Z.index()], z, "Inputs {:?}", digits);
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::rc::Rc;
+
+use super::{AluDatum, AluInteger, Instruction, Operator, Program, Variable, VARIABLES};
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Term {
+    Const(AluInteger),
+    // The n-th digit read, starting at 1
+    Digit(usize),
+    // A variable as left by the block reading the n-th digit, with its definition
+    Symbol(Variable, usize, Rc<Expr>),
+    Op(Operator, Rc<Expr>, Rc<Expr>),
+}
+
+//
+// An expression over the input digits, with the interval its value is known
+// to lie in. Expressions are simplified as they are built, so any expression
+// whose interval is a single value is a constant, unless it is saturated: its
+// exact interval did not fit in an AluInteger and was clamped, so computing
+// it may overflow.
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct Expr {
+    pub term: Term,
+    pub low: AluInteger,
+    pub high: AluInteger,
+    pub saturated: bool,
+}
+
+impl Expr {
+    pub fn constant(value: AluInteger) -> Rc<Expr> {
+        return Rc::new(Expr{term: Term::Const(value), low: value, high: value, saturated: false});
+    }
+
+    pub fn digit(n: usize) -> Rc<Expr> {
+        return Rc::new(Expr{term: Term::Digit(n), low: 1, high: 9, saturated: false});
+    }
+
+    pub fn symbol(var: Variable, n: usize, definition: Rc<Expr>) -> Rc<Expr> {
+        let (low, high, saturated) = (definition.low, definition.high, definition.saturated);
+        return Rc::new(Expr{term: Term::Symbol(var, n, definition), low: low, high: high, saturated: saturated});
+    }
+
+    pub fn as_const(&self) -> Option<AluInteger> {
+        return if self.low == self.high && !self.saturated { Some(self.low) } else { None };
+    }
+
+    // The expression behind any symbols
+    fn definition(&self) -> &Expr {
+        return match &self.term {
+            Term::Symbol(_, _, definition) => definition.definition(),
+            _ => self,
+        };
+    }
+
+    // Matches e * k + r with e >= 0 and 0 <= r < k, as pushed onto a base k stack
+    fn as_pushed(&self, k: AluInteger) -> Option<(&Rc<Expr>, &Rc<Expr>)> {
+        if let Term::Op(Operator::Add, product, rest) = &self.definition().term {
+            if let Term::Op(Operator::Mul, e, factor) = &product.definition().term {
+                if factor.as_const() == Some(k) && e.low >= 0 && rest.low >= 0 && rest.high < k {
+                    return Some((e, rest));
+                }
+            }
+        }
+        return None;
+    }
+
+    //
+    // Builds a simplified a <operator> b: constants are folded, identities
+    // dropped and operations whose result is decided by the intervals of the
+    // operands replaced by their result
+    //
+    pub fn apply(operator: Operator, a: Rc<Expr>, b: Rc<Expr>) -> Rc<Expr> {
+        if let (Some(x), Some(y)) = (a.as_const(), b.as_const()) {
+            if let Some(value) = operator.apply(x, y) {
+                return Expr::constant(value);
+            }
+        }
+        if let Some(simplified) = simplify(operator, &a, &b) {
+            return simplified;
+        }
+
+        // Anything computed from a value that may overflow may overflow too
+        let (low, high, clamped) = bounds(operator, &a, &b);
+        let saturated = clamped || a.saturated || b.saturated;
+        if low == high && !saturated {
+            return Expr::constant(low);
+        }
+        return Rc::new(Expr{term: Term::Op(operator, a, b), low: low, high: high, saturated: saturated});
+    }
+}
+
+fn simplify(operator: Operator, a: &Rc<Expr>, b: &Rc<Expr>) -> Option<Rc<Expr>> {
+    let (x, y) = (a.as_const(), b.as_const());
+    return match operator {
+        // Constants go to the right so they can be combined
+        Operator::Add | Operator::Mul if x.is_some() && y.is_none() => Some(Expr::apply(operator, b.clone(), a.clone())),
+        Operator::Add if y == Some(0) => Some(a.clone()),
+        Operator::Add => {
+            if let (Term::Op(Operator::Add, e, c), Some(y)) = (&a.term, y) {
+                let sum = c.as_const()?.checked_add(y)?;
+                return Some(Expr::apply(Operator::Add, e.clone(), Expr::constant(sum)));
+            }
+            None
+        }
+        Operator::Mul if y == Some(0) => Some(Expr::constant(0)),
+        Operator::Mul if y == Some(1) => Some(a.clone()),
+        Operator::Div if y == Some(1) => Some(a.clone()),
+        Operator::Div => a.as_pushed(y?).map(|(e, _)| e.clone()),
+        Operator::Mod if a.low >= 0 && y.is_some_and(|k| a.high < k) => Some(a.clone()),
+        Operator::Mod => a.as_pushed(y?).map(|(_, rest)| rest.clone()),
+        Operator::Eql if a.high < b.low || b.high < a.low => Some(Expr::constant(0)),
+        _ => None,
+    };
+}
+
+//
+// The interval of a <operator> b, everything if it cannot be narrowed down,
+// and whether it was clamped to the AluInteger range
+//
+fn bounds(operator: Operator, a: &Expr, b: &Expr) -> (AluInteger, AluInteger, bool) {
+    let everything = (AluInteger::MIN, AluInteger::MAX, false);
+    let corners = |f: fn(i128, i128) -> i128| {
+        let values = [f(a.low as i128, b.low as i128), f(a.low as i128, b.high as i128),
+                      f(a.high as i128, b.low as i128), f(a.high as i128, b.high as i128)];
+        let (low, high) = (*values.iter().min().unwrap(), *values.iter().max().unwrap());
+        let clamp = |v: i128| v.clamp(AluInteger::MIN as i128, AluInteger::MAX as i128) as AluInteger;
+        let clamped = low < AluInteger::MIN as i128 || high > AluInteger::MAX as i128;
+        return (clamp(low), clamp(high), clamped);
+    };
+
+    return match operator {
+        Operator::Add => corners(|a, b| a + b),
+        Operator::Mul => corners(|a, b| a.saturating_mul(b)),
+        // Division truncates, which is monotonic as long as the divisor keeps its sign
+        Operator::Div if b.low > 0 || b.high < 0 => corners(|a, b| a / b),
+        Operator::Mod if a.low >= 0 && b.low > 0 => (0, a.high.min(b.high - 1), false),
+        Operator::Eql => (0, 1, false),
+        _ => everything,
+    };
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn operand(f: &mut fmt::Formatter<'_>, e: &Expr) -> fmt::Result {
+            return match e.term {
+                Term::Op(..) => write!(f, "({})", e),
+                _ => write!(f, "{}", e),
+            };
+        }
+
+        return match &self.term {
+            Term::Const(value) => write!(f, "{}", value),
+            Term::Digit(n) => write!(f, "d{}", n),
+            Term::Symbol(var, n, _) => write!(f, "{}{}", var, n),
+            // The ALU has no inequality, it compares the comparison with 0
+            Term::Op(Operator::Eql, a, b) if b.as_const() == Some(0) && matches!(a.term, Term::Op(Operator::Eql, ..)) => {
+                let Term::Op(_, p, q) = &a.term else { unreachable!() };
+                operand(f, p)?;
+                write!(f, " != ")?;
+                operand(f, q)
+            }
+            Term::Op(Operator::Add, a, b) if b.as_const().is_some_and(|c| c < 0) => {
+                operand(f, a)?;
+                write!(f, " - {}", b.low.unsigned_abs())
+            }
+            Term::Op(operator, a, b) => {
+                operand(f, a)?;
+                write!(f, " {} ", operator.symbol())?;
+                operand(f, b)
+            }
+        };
+    }
+}
+
+// The variables changed by the instructions up to the next input
+#[derive(Debug)] #[derive(Clone)]
+pub struct Block {
+    // The digit read at the start of the block, 0 for instructions before the first input
+    pub digit: usize,
+    pub assignments: Vec<(Variable, Rc<Expr>)>,
+}
+
+//
+// A program executed on expressions instead of values. At every input the
+// compound values computed by the previous block are replaced by symbols, so
+// each formula stays as small as its block.
+//
+#[derive(Debug)]
+pub struct SymbolicProgram {
+    pub blocks: Vec<Block>,
+    registers: [Rc<Expr>; 4],
+}
+
+impl SymbolicProgram {
+    pub fn execute(program: &Program) -> SymbolicProgram {
+        let mut registers = [Expr::constant(0), Expr::constant(0), Expr::constant(0), Expr::constant(0)];
+        let mut symbolic = SymbolicProgram{blocks: Vec::new(), registers: registers.clone()};
+        let mut digit = 0;
+
+        for instr in program {
+            if let Instruction::Inp(var) = instr {
+                symbolic.close_block(digit, &mut registers);
+                digit += 1;
+                registers[var.index()] = Expr::digit(digit);
+                continue;
+            }
+            let (var, operator, datum) = Operator::of(instr).unwrap();
+            let operand = match datum {
+                AluDatum::Var(source) => registers[source.index()].clone(),
+                AluDatum::Literal(value) => Expr::constant(value),
+            };
+            registers[var.index()] = Expr::apply(operator, registers[var.index()].clone(), operand);
+        }
+        symbolic.close_block(digit, &mut registers);
+        symbolic.registers = registers;
+        return symbolic;
+    }
+
+    fn close_block(&mut self, digit: usize, registers: &mut [Rc<Expr>; 4]) {
+        let start = self.registers.clone();
+        let mut assignments = Vec::new();
+        for (i, var) in VARIABLES.iter().enumerate() {
+            if Rc::ptr_eq(&registers[i], &start[i]) {
+                continue;
+            }
+            assignments.push((*var, registers[i].clone()));
+            if matches!(registers[i].term, Term::Op(..)) {
+                registers[i] = Expr::symbol(*var, digit, registers[i].clone());
+            }
+        }
+        self.registers = registers.clone();
+        self.blocks.push(Block{digit: digit, assignments: assignments});
+    }
+
+    // The value of a variable at the end of the program
+    pub fn value(&self, var: Variable) -> &Rc<Expr> {
+        return &self.registers[var.index()];
+    }
+
+    //
+    // The blocks with only the assignments the final value of the outputs
+    // depends on, blocks left without assignments are dropped
+    //
+    pub fn live_blocks(&self, outputs: &[Variable]) -> Vec<Block> {
+        fn mark(e: &Expr, live: &mut HashSet<(Variable, usize)>) {
+            match &e.term {
+                Term::Symbol(var, n, definition) => {
+                    if live.insert((*var, *n)) {
+                        mark(definition, live);
+                    }
+                }
+                Term::Op(_, a, b) => {
+                    mark(a, live);
+                    mark(b, live);
+                }
+                Term::Const(_) | Term::Digit(_) => {}
+            }
+        }
+
+        let mut live = HashSet::new();
+        for var in outputs {
+            mark(self.value(*var), &mut live);
+        }
+
+        let mut blocks = Vec::new();
+        for block in &self.blocks {
+            let assignments: Vec<(Variable, Rc<Expr>)> = block.assignments.iter()
+                .filter(|(var, _)| live.contains(&(*var, block.digit)))
+                .cloned()
+                .collect();
+            if !assignments.is_empty() {
+                blocks.push(Block{digit: block.digit, assignments: assignments});
+            }
+        }
+        return blocks;
+    }
+
+    //
+    // The formulas of the outputs, one section per digit, e.g.
+    //
+    //   d2:
+    //     z2 = (z1 * 26) + (d2 + 12)  in [208, 411]
+    //   z = z2
+    //
+    pub fn render(&self, outputs: &[Variable]) -> String {
+        let mut res = String::new();
+        for block in self.live_blocks(outputs) {
+            if block.digit == 0 {
+                res += "start:\n";
+            } else {
+                res += &format!("d{}:\n", block.digit);
+            }
+            for (var, value) in &block.assignments {
+                res += &format!("  {}{} = {}  in [{}, {}]\n", var, block.digit, value, value.low, value.high);
+            }
+        }
+        for var in outputs {
+            res += &format!("{} = {}\n", var, self.value(*var));
+        }
+        return res;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day24::{Day24, MonadBlock};
+    use crate::solution::Solution;
+
+    fn monad(blocks: &[MonadBlock]) -> SymbolicProgram {
+        let program: Program = blocks.iter().flat_map(|b| b.instructions()).collect();
+        return SymbolicProgram::execute(&program);
+    }
+
+    #[test]
+    fn pushes_simplify_to_stack_operations() {
+        let push = MonadBlock{divisor: 1, check: 11, offset: 6};
+        let symbolic = monad(&[push, push]);
+        assert_eq!(symbolic.render(&[Variable::Z]), "d1:\n  z1 = d1 + 6  in [7, 15]\nd2:\n  z2 = (z1 * 26) + (d2 + 6)  in [189, 405]\nz = z2\n");
+    }
+
+    #[test]
+    fn pops_look_through_symbols() {
+        let push = MonadBlock{divisor: 1, check: 11, offset: 6};
+        let pop = MonadBlock{divisor: 26, check: -8, offset: 3};
+        let symbolic = monad(&[push, push, pop]);
+
+        // z2 is popped right away, so z3 only depends on z1
+        assert_eq!(symbolic.render(&[Variable::Z]), "d1:\n  z1 = d1 + 6  in [7, 15]\n\
+                                                    d3:\n  z3 = (z1 * ((((d2 - 2) != d3) * 25) + 1)) + ((d3 + 3) * ((d2 - 2) != d3))  in [7, 402]\n\
+                                                    z = z3\n");
+    }
+
+    #[test]
+    fn folds_constants_and_decided_comparisons() {
+        let program = Day24::parse("inp x\nadd x 10\neql x 5\nadd y 3\nmul y 4\ninp z\ndiv z 2").unwrap();
+        let symbolic = SymbolicProgram::execute(&program);
+        assert_eq!(symbolic.value(Variable::X).as_const(), Some(0));
+        assert_eq!(symbolic.value(Variable::Y).as_const(), Some(12));
+        assert_eq!((symbolic.value(Variable::Z).low, symbolic.value(Variable::Z).high), (0, 4));
+        assert_eq!(symbolic.render(&[Variable::Z]), "d2:\n  z2 = d2 / 2  in [0, 4]\nz = z2\n");
+
+        // Division by zero is left for the ALU to fail on
+        let program = Day24::parse("inp x\ndiv x 0").unwrap();
+        assert_eq!(SymbolicProgram::execute(&program).value(Variable::X).to_string(), "x1");
+    }
+
+    #[test]
+    fn keeps_overflowing_values() {
+        // d1 * 10^20 overflows for every digit, the clamped interval is the single value MAX
+        let program = Day24::parse("inp x\nmul x 1000000000\nmul x 1000000000\nmul x 100\nadd y x\ndiv y 2").unwrap();
+        let symbolic = SymbolicProgram::execute(&program);
+        let x = symbolic.value(Variable::X);
+        assert_eq!((x.low, x.high, x.saturated), (AluInteger::MAX, AluInteger::MAX, true));
+        assert_eq!(x.as_const(), None);
+        assert_eq!(symbolic.render(&[Variable::X]), "d1:\n  x1 = ((d1 * 1000000000) * 1000000000) * 100  in [9223372036854775807, 9223372036854775807]\nx = x1\n");
+
+        // Values computed from it are not folded either, though halving it fits
+        assert_eq!(symbolic.value(Variable::Y).as_const(), None);
+        assert!(symbolic.value(Variable::Y).saturated);
+        let program = Day24::parse("inp x\nmul x 1000000000\nmul x 1000000000").unwrap();
+        assert!(!SymbolicProgram::execute(&program).value(Variable::X).saturated);
+    }
+}
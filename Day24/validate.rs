@@ -0,0 +1,103 @@
+use std::rc::Rc;
+
+use super::symbolic::Expr;
+use super::{AluDatum, AluError, Instruction, Operator, Program};
+
+//
+// The errors the program can run into when given the number of inputs, each
+// a digit from 1 to 9. Registers are followed as expressions with the
+// interval of their value, an instruction is flagged whenever the intervals
+// of its operands allow an error. That may flag instructions no combination
+// of digits actually reaches, but never misses one. For an invalid modulo the
+// operands are a pair from their intervals for which it fails.
+//
+// Once an error is certain, such as reading more inputs than given, the
+// instructions after it are unreachable and not checked.
+//
+pub fn validate(program: &Program, inputs: usize) -> Vec<AluError> {
+    let mut registers = [Expr::constant(0), Expr::constant(0), Expr::constant(0), Expr::constant(0)];
+    let mut digit = 0;
+    let mut errors = Vec::new();
+
+    for (index, instr) in program.iter().enumerate() {
+        if let Instruction::Inp(var) = instr {
+            if digit == inputs {
+                errors.push(AluError::InputExhausted{index: index});
+                break;
+            }
+            digit += 1;
+            registers[var.index()] = Expr::digit(digit);
+            continue;
+        }
+
+        let (var, operator, datum) = Operator::of(instr).unwrap();
+        let a = registers[var.index()].clone();
+        let b = match datum {
+            AluDatum::Var(source) => registers[source.index()].clone(),
+            AluDatum::Literal(value) => Expr::constant(value),
+        };
+        if let Some((error, certain)) = hazard(operator, &a, &b, index) {
+            errors.push(error);
+            if certain {
+                break;
+            }
+        }
+        // Flagged where the overflow starts, the values computed from it are saturated too
+        let result = Expr::apply(operator, a.clone(), b.clone());
+        if result.saturated && !a.saturated && !b.saturated {
+            errors.push(AluError::Overflow{index: index});
+        }
+        registers[var.index()] = result;
+    }
+    return errors;
+}
+
+// The error the operation may run into, and whether it always does
+fn hazard(operator: Operator, a: &Rc<Expr>, b: &Rc<Expr>, index: usize) -> Option<(AluError, bool)> {
+    return match operator {
+        Operator::Div if b.low <= 0 && b.high >= 0 => Some((AluError::DivideByZero{index: index}, b.as_const() == Some(0))),
+        Operator::Mod if a.low < 0 || b.low <= 0 => {
+            let modulus = if a.low < 0 { b.high } else { b.low };
+            let error = AluError::InvalidModulo{index: index, value: a.low, modulus: modulus};
+            Some((error, a.high < 0 || b.high <= 0))
+        }
+        _ => None,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day24::{Day24, MonadBlock};
+    use crate::solution::Solution;
+
+    #[test]
+    fn accepts_monad() {
+        let mut program = Program::new();
+        for (divisor, check, offset) in [(1, 11, 6), (1, 13, 2), (26, -4, 7), (1, 10, 1), (26, -10, 3), (26, 0, 5)] {
+            program.extend(MonadBlock{divisor: divisor, check: check, offset: offset}.instructions());
+        }
+        assert_eq!(validate(&program, 6), vec![]);
+        assert_eq!(validate(&program, 5), vec![AluError::InputExhausted{index: 90}]);
+    }
+
+    #[test]
+    fn flags_reachable_errors() {
+        let program = Day24::parse("inp x\ndiv x 0\nadd y 1").unwrap();
+        assert_eq!(validate(&program, 1), vec![AluError::DivideByZero{index: 1}]);
+
+        // x - 5 is 0 for the digit 5 only, but that is enough
+        let program = Day24::parse("inp x\nadd x -5\ndiv z x\nmod x 3\ninp y").unwrap();
+        assert_eq!(validate(&program, 1), vec![AluError::DivideByZero{index: 2},
+                                               AluError::InvalidModulo{index: 3, value: -4, modulus: 3},
+                                               AluError::InputExhausted{index: 4}]);
+
+        // The comparison leaves 1 in x, so x + w is never 0
+        let program = Day24::parse("inp w\neql x 0\nadd x w\nmod w x\ndiv w x").unwrap();
+        assert_eq!(validate(&program, 1), vec![]);
+
+        // 9 * 10^18 still fits, times 10 it does not
+        let program = Day24::parse("inp x\nmul x 1000000000\nmul x 1000000000\nmul x 10\nadd y x").unwrap();
+        assert_eq!(validate(&program, 1), vec![AluError::Overflow{index: 3}]);
+    }
+}
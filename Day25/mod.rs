@@ -0,0 +1,86 @@
+use crate::error::ParseError;
+use crate::grid::{Grid, Pos};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day25;
+
+#[derive(Clone)] #[derive(PartialEq)]
+pub enum Facing {
+    Right,
+    Down
+}
+
+#[derive(Clone)] #[derive(PartialEq)]
+pub enum Tile {
+    Empty,
+    SeaCucumber(Facing),
+}
+
+#[derive(Clone)]
+pub struct SeaCucumberMap {
+    // Wrapping, the herds leaving one edge reappear on the opposite one
+    map: Grid<Tile>,
+}
+
+impl SeaCucumberMap {
+    pub fn render(&self) -> String {
+        return self.map.render(|tile| match tile {
+            Tile::Empty => '.',
+            Tile::SeaCucumber(Facing::Right) => '>',
+            Tile::SeaCucumber(Facing::Down) => 'v',
+        });
+    }
+
+    // Moves every cucumber of the herd facing the given way, returns how many moved
+    fn move_herd(&mut self, facing: Facing, delta: Pos) -> usize {
+        let mut moved = 0;
+        let mut next_state = self.map.clone();
+
+        for (pos, tile) in self.map.iter() {
+            if *tile == Tile::SeaCucumber(facing.clone()) {
+                let next_pos = self.map.offset(pos, delta).unwrap();
+                if self.map[next_pos] == Tile::Empty {
+                    moved += 1;
+                    next_state[next_pos] = tile.clone();
+                    next_state[pos] = Tile::Empty;
+                }
+            }
+        }
+        self.map = next_state;
+        return moved;
+    }
+
+    pub fn update(&mut self) -> usize {
+        return self.move_herd(Facing::Right, (1, 0)) + self.move_herd(Facing::Down, (0, 1));
+    }
+}
+
+impl Solution for Day25 {
+    type Input = SeaCucumberMap;
+
+    const PARTS: u8 = 1;
+
+    fn parse(data: &str) -> Result<SeaCucumberMap, ParseError> {
+        let map = Grid::parse(25, data, "Unknown tile", |c| match c {
+            '.' => Some(Tile::Empty),
+            '>' => Some(Tile::SeaCucumber(Facing::Right)),
+            'v' => Some(Tile::SeaCucumber(Facing::Down)),
+            _ => None,
+        })?;
+        return Ok(SeaCucumberMap{map: map.wrapping(true)});
+    }
+
+    fn part1(cucumber_map: &SeaCucumberMap) -> Result<Answer, NoAnswer> {
+        let mut cucumber_map = cucumber_map.clone();
+
+        let mut step = 1;
+        while cucumber_map.update() > 0 {
+            step += 1;
+        }
+        return Ok(step.into());
+    }
+
+    fn part2(_cucumber_map: &SeaCucumberMap) -> Result<Answer, NoAnswer> {
+        return Err(NoAnswer::new(25, "Day 25 only has one part"));
+    }
+}
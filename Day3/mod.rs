@@ -0,0 +1,117 @@
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day3;
+
+pub struct DiagnosticReport {
+    pub strings: Vec<Vec<u8>>,
+    pub bitcount: usize,
+}
+
+fn compute_most_common(data: &Vec<Vec<u8>>, bitcount: usize) -> Vec<Option<u8>> {
+    let mut balances: Vec<i32> = vec![0; bitcount];
+
+    for bit_string in data {
+        for i in 0 .. bitcount {
+            if bit_string[i] == 1 {
+                balances[i] += 1;
+            } else {
+                balances[i] -= 1;
+            }
+        }
+    }
+
+    let mut result: Vec<Option<u8>> = vec![None; bitcount];
+
+    for (i, balance) in balances.iter().enumerate() {
+        if *balance > 0 {
+            result[i] = Some(1);
+        }
+        else if *balance < 0 {
+            result[i] = Some(0);
+        }
+    }
+
+    return result;
+}
+
+type CriteriaF = fn(val: u8, most_common: Option<u8>) -> bool;
+
+fn compute_with_criteria(data: &Vec<Vec<u8>>, bitindex: usize, criteria: CriteriaF) -> u32 {
+    let bitcount = data[0].len();
+
+    if data.len() == 1 {
+        let mut result = 0u32;
+        for (i, bit) in data[0].iter().enumerate() {
+            if *bit == 1 {
+                result |= 1 << (bitcount - i - 1);
+            }
+        }
+        return result;
+    }
+
+    let mut new_candidates: Vec<Vec<u8>> = Vec::new();
+
+    let most_common = compute_most_common(data, bitcount);
+    for candidate in data {
+        if criteria(candidate[bitindex], most_common[bitindex]) {
+            new_candidates.push(candidate.clone());
+        }
+    }
+
+    return compute_with_criteria(&new_candidates, bitindex + 1, criteria);
+}
+
+impl Solution for Day3 {
+    type Input = DiagnosticReport;
+
+    fn parse(data: &str) -> Result<DiagnosticReport, ParseError> {
+        let mut result: Vec<Vec<u8>> = Vec::new();
+        for elem in data.split("\n") {
+            let mut loc_result: Vec<u8> = Vec::new();
+            for (i, c) in elem.char_indices() {
+                match c {
+                    '1' => loc_result.push(1),
+                    '0' => loc_result.push(0),
+                    _ => return Err(ParseError::at(3, data, error::char_at(elem, i), "Expected a bit")),
+                }
+            }
+            if !result.is_empty() && loc_result.len() != result[0].len() {
+                return Err(ParseError::at(3, data, elem, &format!("Expected {} bits", result[0].len())));
+            }
+            result.push(loc_result);
+        }
+        let length = result[0].len();
+        return Ok(DiagnosticReport{strings: result, bitcount: length});
+    }
+
+    fn part1(report: &DiagnosticReport) -> Result<Answer, NoAnswer> {
+        let bitcount = report.bitcount;
+        let most_common = compute_most_common(&report.strings, bitcount);
+
+        let mut gamma = 0u32;
+        for (i, common) in most_common.iter().enumerate() {
+            if *common == Some(1) {
+                gamma |= 1 << (bitcount - i - 1);
+            }
+        }
+        let mut mask = 0u32;
+        for i in 0 .. bitcount {
+            mask |= 1 << i;
+        }
+        let epsilon = !gamma & mask;
+
+        return Ok((gamma * epsilon).into());
+    }
+
+    fn part2(report: &DiagnosticReport) -> Result<Answer, NoAnswer> {
+        let oxygen_rating = compute_with_criteria(&report.strings, 0,
+            |val, most_common| Some(val) == most_common || (most_common.is_none() && val == 1)
+        );
+        let scrubber_rating = compute_with_criteria(&report.strings, 0,
+            |val, most_common| (most_common.is_some() && Some(val) != most_common) || (most_common.is_none() && val == 0)
+        );
+
+        return Ok((oxygen_rating * scrubber_rating).into());
+    }
+}
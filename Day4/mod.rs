@@ -0,0 +1,149 @@
+use std::fmt;
+use std::collections::HashSet;
+
+use crate::error::{self, ParseError};
+use crate::grid::Grid;
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day4;
+
+pub struct Bingo {
+    pub draws: Vec<i32>,
+    pub boards: Vec<BingoBoard>,
+}
+
+#[derive(Clone)]
+pub struct BingoBoard {
+    vals : Grid<i32>,
+    marks : Grid<bool>,
+}
+
+impl BingoBoard {
+    pub fn new(values: Grid<i32>) -> Self {
+        let marks = values.map(|_| false);
+        return Self{ vals: values, marks: marks };
+    }
+
+    fn score(&self, last_draw: i32) -> i32{
+        let mut total = 0;
+        for (pos, val) in self.vals.iter() {
+            if !self.marks[pos] {
+                total += val;
+            }
+        }
+        return total * last_draw;
+    }
+
+    fn check_row_completed(&self, y: i64) -> bool {
+        return (0 .. self.marks.width()).all(|x| self.marks[(x, y)]);
+    }
+    fn check_column_completed(&self, x: i64) -> bool {
+        return (0 .. self.marks.height()).all(|y| self.marks[(x, y)]);
+    }
+
+    //
+    // Returns true if the board got bingo due to this mark
+    // false otherwise
+    //
+    fn mark_value(&mut self, val: i32) -> bool {
+        let found = self.vals.iter().find(|(_, v)| **v == val).map(|(pos, _)| pos);
+        if let Some((x, y)) = found {
+            self.marks[(x, y)] = true;
+            return self.check_column_completed(x) || self.check_row_completed(y);
+        }
+        return false;
+    }
+
+}
+
+impl fmt::Display for BingoBoard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (pos, val) in self.vals.iter() {
+            write!(f, "{}{:3} ", if self.marks[pos] { '#' } else { ' ' }, val)?;
+            if pos.0 == self.vals.width() - 1 {
+                writeln!(f)?;
+            }
+        }
+        return fmt::Result::Ok(());
+    }
+} 
+
+//
+// Boards are square, each row on its own line and the numbers separated by
+// spaces
+//
+fn parse_board(data: &str, rows: &[&str]) -> Result<BingoBoard, ParseError> {
+    let mut cells = Vec::new();
+    for row in rows {
+        let row_values : Vec<i32> = row.split_whitespace().map(|s| error::parse_number(4, data, s)).collect::<Result<_, _>>()?;
+        if row_values.len() != rows.len() {
+            return Err(ParseError::at(4, data, row, &format!("Expected {} numbers", rows.len())));
+        }
+        cells.extend(row_values);
+    }
+    return Ok(BingoBoard::new(Grid::from_cells(rows.len() as i64, cells)));
+}
+
+impl Solution for Day4 {
+    type Input = Bingo;
+
+    fn parse(data: &str) -> Result<Bingo, ParseError> {
+        let mut draws: Vec<i32> = Vec::new();
+        let mut boards: Vec<BingoBoard> = Vec::new();
+
+        let mut current_board: Vec<&str> = Vec::new();
+        for (index, elem) in data.split("\n").enumerate() {
+            if index == 0 {
+                draws = elem.split(",").map(|s| error::parse_number(4, data, s)).collect::<Result<_, _>>()?;
+            } else if elem.is_empty() {
+                if !current_board.is_empty() {
+                    boards.push(parse_board(data, &current_board)?);
+                }
+                current_board = Vec::new();
+            } else {
+                current_board.push(elem);
+            }
+        }
+
+        if !current_board.is_empty() {
+            boards.push(parse_board(data, &current_board)?);
+        }
+
+        return Ok(Bingo{draws: draws, boards: boards});
+    }
+
+    fn part1(bingo: &Bingo) -> Result<Answer, NoAnswer> {
+        let mut boards = bingo.boards.clone();
+        let mut winning_score = 0;
+
+        'drawer: for draw in &bingo.draws {
+            for board in boards.iter_mut() {
+                if board.mark_value(*draw) {
+                    winning_score = board.score(*draw);
+                    break 'drawer;
+                }
+            }
+        }
+        return Ok(winning_score.into());
+    }
+
+    fn part2(bingo: &Bingo) -> Result<Answer, NoAnswer> {
+        let mut boards = bingo.boards.clone();
+        let mut winning_score = 0;
+        let mut winners: HashSet<usize> = HashSet::new();
+
+        let boards_count: usize = boards.len();
+        'drawer: for draw in &bingo.draws {
+            for (board_index, board) in boards.iter_mut().enumerate() {
+                if board.mark_value(*draw) {
+                    winners.insert(board_index);
+                    if winners.len() == boards_count {
+                        winning_score = board.score(*draw);
+                        break 'drawer;
+                    }
+                }
+            }
+        }
+        return Ok(winning_score.into());
+    }
+}
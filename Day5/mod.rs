@@ -0,0 +1,74 @@
+use std::cmp;
+use std::collections::HashMap;
+use regex::Regex;
+
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day5;
+
+type Point = (i32, i32);
+
+pub type VentRange = (Point, Point);
+
+fn build_map(ranges: &Vec<VentRange>, with_diagonals: bool) -> HashMap<Point, u32> {
+    let mut map: HashMap<Point, u32> = HashMap::new();
+
+    let mut add_point = |point: Point| {
+        *map.entry(point).or_insert(0) += 1;
+    };
+    for range in ranges {
+        if range.0.1 == range.1.1 {
+            for x in cmp::min(range.0.0, range.1.0) .. cmp::max(range.0.0, range.1.0) + 1 {
+                add_point((x, range.0.1));
+            }
+        } else if range.0.0 == range.1.0 {
+            for y in cmp::min(range.0.1, range.1.1) .. cmp::max(range.0.1, range.1.1) + 1 {
+                add_point((range.0.0, y));
+            }
+        } else if with_diagonals {
+            // 45 degree lines are allowed
+            let mut cur_x = range.0.0;
+            let mut cur_y = range.0.1;
+            let end_x = range.1.0;
+            let end_y = range.1.1;
+
+            let step_x = if cur_x < end_x {1} else {-1};
+            let step_y = if cur_y < end_y {1} else {-1};
+
+            while cur_x != end_x + step_x && cur_y != end_y + step_y {
+                add_point((cur_x, cur_y));
+                cur_x += step_x;
+                cur_y += step_y;
+            }
+        }
+    }
+    return map;
+}
+
+fn count_overlaps(map: &HashMap<Point, u32>) -> usize {
+    return map.values().filter(|danger| **danger >= 2).count();
+}
+
+impl Solution for Day5 {
+    type Input = Vec<VentRange>;
+
+    fn parse(data: &str) -> Result<Vec<VentRange>, ParseError> {
+        let mut result: Vec<VentRange> = Vec::new();
+        let re = Regex::new(r"^(\d+),(\d+) -> (\d+),(\d+)$").unwrap();
+        for elem in data.split("\n") {
+            let cap = re.captures(elem).ok_or_else(|| ParseError::at(5, data, elem, "Expected \"x1,y1 -> x2,y2\""))?;
+            let coord = |i: usize| error::parse_number::<i32>(5, data, cap.get(i).unwrap().as_str());
+            result.push(((coord(1)?, coord(2)?), (coord(3)?, coord(4)?)));
+        }
+        return Ok(result);
+    }
+
+    fn part1(ranges: &Vec<VentRange>) -> Result<Answer, NoAnswer> {
+        return Ok(count_overlaps(&build_map(ranges, false)).into());
+    }
+
+    fn part2(ranges: &Vec<VentRange>) -> Result<Answer, NoAnswer> {
+        return Ok(count_overlaps(&build_map(ranges, true)).into());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day6;
+
+const MAX_CYCLES: usize = 8;
+
+fn simulate(fish: &[u64], days: u32) -> u64 {
+    let mut cycles: Vec<u64> = vec![0; MAX_CYCLES + 1];
+    for cycles_left in fish {
+        cycles[*cycles_left as usize] += 1;
+    }
+
+    for _day in 0 .. days {
+        let resets = cycles[0];
+        for i in 1 .. cycles.len() {
+            cycles[i - 1] = cycles[i];
+        }
+        cycles[6] += resets;
+        cycles[8] = resets;
+    }
+
+    return cycles.iter().sum::<u64>();
+}
+
+impl Solution for Day6 {
+    type Input = Vec<u64>;
+
+    fn parse(data: &str) -> Result<Vec<u64>, ParseError> {
+        let mut cycles: Vec<u64> = Vec::new();
+        for elem in data.split(",") {
+            let cycles_left = error::parse_number(6, data, elem)?;
+            if cycles_left > MAX_CYCLES as u64 {
+                return Err(ParseError::at(6, data, elem, "Timer out of range"));
+            }
+            cycles.push(cycles_left);
+        }
+        return Ok(cycles);
+    }
+
+    fn part1(fish: &Vec<u64>) -> Result<Answer, NoAnswer> {
+        return Ok(simulate(fish, 80).into());
+    }
+
+    fn part2(fish: &Vec<u64>) -> Result<Answer, NoAnswer> {
+        return Ok(simulate(fish, 256).into());
+    }
+}
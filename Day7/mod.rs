@@ -0,0 +1,80 @@
+use std::cmp;
+use std::collections::HashMap;
+
+use crate::error::{self, ParseError};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day7;
+
+struct TargetCandidate {
+    crabs : i64,
+    l_fuel : i64,
+    r_fuel : i64,
+}
+
+// With increasing burn every step costs one more fuel than the last
+fn best_fuel(positions: &Vec<i64>, increasing_burn: bool) -> i64 {
+    let mut target_pos_info: HashMap<i64, TargetCandidate> = HashMap::new();
+
+    let mut min = i64::MAX;
+    let mut max = i64::MIN;
+    for pos in positions {
+        min = cmp::min(min, *pos);
+        max = cmp::max(max, *pos);
+    }
+
+    for pos in positions {
+        target_pos_info.entry(*pos)
+                       .or_insert(TargetCandidate{crabs: 0, l_fuel: 0, r_fuel: 0})
+                       .crabs += 1;
+    }
+
+    let mut l_fuel = 0;
+    let mut l_burn = 0;
+    let mut l_crabs = 0;
+    for pos in min .. max + 1 {
+        l_burn += l_crabs;
+        l_fuel += if increasing_burn { l_burn } else { l_crabs };
+        let target = target_pos_info.entry(pos)
+                                    .or_insert(TargetCandidate{crabs: 0, l_fuel: 0, r_fuel: 0});
+        target.l_fuel = l_fuel;
+        l_crabs += target.crabs;
+    }
+
+    let mut r_fuel = 0;
+    let mut r_burn = 0;
+    let mut r_crabs = 0;
+    for pos in (min .. max + 1).rev() {
+        r_burn += r_crabs;
+        r_fuel += if increasing_burn { r_burn } else { r_crabs };
+        let target = target_pos_info.get_mut(&pos).unwrap();
+        target.r_fuel = r_fuel;
+        r_crabs += target.crabs;
+    }
+
+    let mut best = i64::MAX;
+    for val in target_pos_info.values() {
+        best = cmp::min(best, val.l_fuel + val.r_fuel);
+    }
+    return best;
+}
+
+impl Solution for Day7 {
+    type Input = Vec<i64>;
+
+    fn parse(data: &str) -> Result<Vec<i64>, ParseError> {
+        let mut poses: Vec<i64> = Vec::new();
+        for elem in data.split(",") {
+            poses.push(error::parse_number(7, data, elem)?);
+        }
+        return Ok(poses);
+    }
+
+    fn part1(positions: &Vec<i64>) -> Result<Answer, NoAnswer> {
+        return Ok(best_fuel(positions, false).into());
+    }
+
+    fn part2(positions: &Vec<i64>) -> Result<Answer, NoAnswer> {
+        return Ok(best_fuel(positions, true).into());
+    }
+}
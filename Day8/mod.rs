@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use crate::error::ParseError;
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day8;
+
+pub struct Entry7SegSignals {
+    pub inputs : Vec<Vec<u8>>,
+    pub ouputs : Vec<Vec<u8>>,
+}
+
+impl Entry7SegSignals {
+    pub fn count_vals(&self, val: u8) -> u32 {
+        let segments = match val {
+            1 => 2,
+            4 => 4,
+            7 => 3,
+            8 => 7,
+            _ => return 0,
+        };
+        return self.ouputs.iter().filter(| outp | outp.len() == segments).count() as u32;
+    }
+
+    pub fn compute_outputs(&self) -> u32 {
+        let structures = Entry7SegSignals::identify_digits(&self.inputs);
+
+        let mut sum = 0u32;
+        let mut factor = 1u32;
+        for output in self.ouputs.iter().rev() {
+            for (val, structure) in &structures {
+                if output.len() == structure.len() &&
+                   Entry7SegSignals::contains_all(output, structure) {
+                    sum += (*val as u32) * factor;
+                    factor *= 10;
+                    break;
+                }
+            }
+        }
+
+        return sum;
+    }
+
+    fn difference(left: &[u8], right: &[u8]) -> Vec<u8> {
+        return left.iter().filter(| signal | !right.contains(signal)).cloned().collect();
+    }
+
+    fn contains_all(l: &[u8], r: &[u8]) -> bool {
+        for c in r {
+            if !l.contains(c) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    fn identify_digits(inputs: &Vec<Vec<u8>>) -> HashMap<u8, Vec<u8>> {
+        let mut structures: HashMap<u8, Vec<u8>> = HashMap::new();
+
+        // Basic ones
+        for input in inputs {
+            if input.len() == 2 {
+                structures.insert(1, input.to_vec());
+            } else if input.len() == 4 {
+                structures.insert(4, input.to_vec());
+            } else if input.len() == 3 {
+                structures.insert(7, input.to_vec());
+            } else if input.len() == 7 {
+                structures.insert(8, input.to_vec());
+            }
+        }
+
+        // Five segments
+        for input in inputs {
+            if input.len() == 5 {
+                if Entry7SegSignals::contains_all(input, &structures[&1]) {
+                    structures.insert(3, input.to_vec());
+                } else if Entry7SegSignals::contains_all(input,
+                            &Entry7SegSignals::difference(&structures[&4], &structures[&1])) {
+                    structures.insert(5, input.to_vec());
+                } else {
+                    structures.insert(2, input.to_vec());
+                }
+            }
+        }
+        // Six segments
+        for input in inputs {
+            if input.len() == 6 {
+                if Entry7SegSignals::contains_all(input, &structures[&4]) {
+                    structures.insert(9, input.to_vec());
+                } else if Entry7SegSignals::contains_all(input, &structures[&7]) {
+                    structures.insert(0, input.to_vec());
+                } else {
+                    structures.insert(6, input.to_vec());
+                }
+            }
+        }
+
+        return structures;
+    }
+}
+
+//
+// Parses the space separated segment patterns, each one made of the segments a-g
+//
+fn parse_signals(data: &str, patterns: &str, count: usize) -> Result<Vec<Vec<u8>>, ParseError> {
+    let signals: Vec<&str> = patterns.split_whitespace().collect();
+    if signals.len() != count {
+        return Err(ParseError::at(8, data, patterns, &format!("Expected {} patterns", count)));
+    }
+    for signal in &signals {
+        if signal.len() > 7 || !signal.bytes().all(|b| (b'a' ..= b'g').contains(&b)) {
+            return Err(ParseError::at(8, data, signal, "Invalid segment pattern"));
+        }
+    }
+    return Ok(signals.iter().map(|s| s.as_bytes().to_vec()).collect());
+}
+
+impl Solution for Day8 {
+    type Input = Vec<Entry7SegSignals>;
+
+    fn parse(data: &str) -> Result<Vec<Entry7SegSignals>, ParseError> {
+        let mut result: Vec<Entry7SegSignals> = Vec::new();
+        for elem in data.split("\n") {
+            let inputs_ouputs = elem.split_once('|').ok_or_else(|| ParseError::at(8, data, elem, "Expected \"<inputs> | <outputs>\""))?;
+            let inputs = parse_signals(data, inputs_ouputs.0, 10)?;
+            let outputs = parse_signals(data, inputs_ouputs.1, 4)?;
+            result.push(Entry7SegSignals{inputs: inputs, ouputs: outputs});
+        }
+        return Ok(result);
+    }
+
+    fn part1(entries: &Vec<Entry7SegSignals>) -> Result<Answer, NoAnswer> {
+        let mut easy_digits = 0;
+        for entry in entries {
+            easy_digits += entry.count_vals(1);
+            easy_digits += entry.count_vals(4);
+            easy_digits += entry.count_vals(7);
+            easy_digits += entry.count_vals(8);
+        }
+        return Ok(easy_digits.into());
+    }
+
+    fn part2(entries: &Vec<Entry7SegSignals>) -> Result<Answer, NoAnswer> {
+        let mut sum = 0;
+        for entry in entries {
+            sum += entry.compute_outputs();
+        }
+        return Ok(sum.into());
+    }
+}
@@ -0,0 +1,76 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::grid::{Grid, Neighbourhood, Pos};
+use crate::solution::{Answer, NoAnswer, Solution};
+
+pub struct Day9;
+
+pub struct Heightmap {
+    map : Grid<i32>,
+}
+
+impl Heightmap {
+    pub fn val(&self, pos: Pos) -> i32 {
+        return self.map[pos];
+    }
+
+    pub fn adjacents(&self, pos: Pos) -> Vec<Pos> {
+        return self.map.neighbours(pos, Neighbourhood::Orthogonal);
+    }
+
+    pub fn lowpoints(&self) -> Vec<Pos> {
+        let mut lowpoints: Vec<Pos> = Vec::new();
+        for (pos, height) in self.map.iter() {
+            if self.adjacents(pos).iter().all(| p | self.val(*p) > *height ) {
+                lowpoints.push(pos);
+            }
+        }
+        return lowpoints;
+    }
+}
+
+type Basin = HashSet<Pos>;
+
+impl Solution for Day9 {
+    type Input = Heightmap;
+
+    fn parse(data: &str) -> Result<Heightmap, ParseError> {
+        let map = Grid::parse(9, data, "Expected a digit", |c| c.to_digit(10).map(|d| d as i32))?;
+        return Ok(Heightmap{map: map});
+    }
+
+    fn part1(map: &Heightmap) -> Result<Answer, NoAnswer> {
+        return Ok(map.lowpoints().iter().map(| lp | 1 + map.val(*lp)).sum::<i32>().into());
+    }
+
+    fn part2(map: &Heightmap) -> Result<Answer, NoAnswer> {
+        let mut basins: Vec<Basin> = Vec::new();
+        for lp in map.lowpoints() {
+            let mut basin: Basin = Basin::new();
+            let mut visit_list: HashSet<Pos> = HashSet::new();
+
+            visit_list.insert(lp);
+            while !visit_list.is_empty() {
+                let pt = visit_list.iter().next().cloned().unwrap();
+                visit_list.remove(&pt);
+                basin.insert(pt);
+                for adj in map.adjacents(pt) {
+                    if map.val(adj) < 9 &&
+                       !visit_list.contains(&adj) && !basin.contains(&adj) {
+                          visit_list.insert(adj);
+                       }
+                }
+            }
+            basins.push(basin);
+        }
+        basins.sort_by_key(|b| Reverse(b.len()));
+
+        let mut product = 1;
+        for basin in basins.iter().take(3) {
+            product *= basin.len();
+        }
+        return Ok(product.into());
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+
+use crate::input::InputSource;
+use crate::registry::Entry;
+use crate::solution::Answer;
+
+/// Location of the manifest relative to the repository root
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+//
+// The recorded answers, read from a small subset of TOML:
+//
+//   [day13.part2]
+//   sample = 17
+//   puzzle = """
+//   ...
+//   """
+//
+// Tables are named after the day and part, keys after the input the answer
+// belongs to. Values are integers or (multi-line) basic strings.
+//
+#[derive(Debug)] #[derive(Default)]
+pub struct Manifest {
+    answers: BTreeMap<(u8, u8, String), String>,
+}
+
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(io::Error),
+    Syntax{line: usize, reason: String},
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Io(error) => write!(f, "Could not read the answers manifest: {}", error),
+            ManifestError::Syntax{line, reason} => write!(f, "Answers manifest line {}: {}", line, reason),
+        }
+    }
+}
+
+impl std::error::Error for ManifestError {}
+
+fn syntax_error<T>(line: usize, reason: &str) -> Result<T, ManifestError> {
+    return Err(ManifestError::Syntax{line: line, reason: reason.to_string()});
+}
+
+// Parses "dayN.partM"
+fn parse_table_name(name: &str, line: usize) -> Result<(u8, u8), ManifestError> {
+    let parsed = name.split_once('.').and_then(|(day, part)| {
+        let day = day.strip_prefix("day")?.parse::<u8>().ok()?;
+        let part = part.strip_prefix("part")?.parse::<u8>().ok()?;
+        return Some((day, part));
+    });
+    return match parsed {
+        Some(key) => Ok(key),
+        None => syntax_error(line, &format!("Expected a [dayN.partM] table, found [{}]", name)),
+    };
+}
+
+// Splits a basic string at its closing quote, skipping escaped quotes
+fn split_basic_string(s: &str, line: usize) -> Result<(&str, &str), ManifestError> {
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            return Ok((&s[.. i], &s[i + 1 ..]));
+        }
+    }
+    return syntax_error(line, "Unterminated string");
+}
+
+fn unescape(body: &str, line: usize) -> Result<String, ManifestError> {
+    let mut result = String::new();
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        result.push(match chars.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('"') => '"',
+            Some('\\') => '\\',
+            _ => return syntax_error(line, "Unknown escape sequence"),
+        });
+    }
+    return Ok(result);
+}
+
+fn expect_line_end(rest: &str, line: usize) -> Result<(), ManifestError> {
+    let rest = rest.trim();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return syntax_error(line, &format!("Unexpected \"{}\"", rest));
+    }
+    return Ok(());
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Manifest, ManifestError> {
+        let mut manifest = Manifest::default();
+        let mut table: Option<(u8, u8)> = None;
+
+        let mut lines = text.lines().enumerate().map(|(i, l)| (i + 1, l));
+        while let Some((line_no, line)) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let (name, rest) = match header.split_once(']') {
+                    Some(split) => split,
+                    None => return syntax_error(line_no, "Unterminated table header"),
+                };
+                expect_line_end(rest, line_no)?;
+                table = Some(parse_table_name(name.trim(), line_no)?);
+                continue;
+            }
+
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => return syntax_error(line_no, "Expected \"<input> = <answer>\""),
+            };
+            let (day, part) = match table {
+                Some(table) => table,
+                None => return syntax_error(line_no, "Answer outside of a [dayN.partM] table"),
+            };
+
+            let answer = if let Some(body) = value.strip_prefix("\"\"\"") {
+                // A newline right after the opening quotes is not part of the string
+                let mut raw = String::from(body);
+                while !raw.contains("\"\"\"") {
+                    let (_, next) = lines.next().ok_or(ManifestError::Syntax{line: line_no, reason: "Unterminated string".to_string()})?;
+                    raw.push('\n');
+                    raw.push_str(next);
+                }
+                let raw = raw.strip_prefix('\n').unwrap_or(&raw);
+                let (body, rest) = raw.split_once("\"\"\"").unwrap();
+                expect_line_end(rest, line_no)?;
+                unescape(body, line_no)?
+            } else if let Some(body) = value.strip_prefix('"') {
+                let (body, rest) = split_basic_string(body, line_no)?;
+                expect_line_end(rest, line_no)?;
+                unescape(body, line_no)?
+            } else {
+                let number = value.split('#').next().unwrap().trim();
+                if number.parse::<i64>().is_err() && number.parse::<u64>().is_err() {
+                    return syntax_error(line_no, &format!("Expected an integer or a string, found \"{}\"", value));
+                }
+                number.to_string()
+            };
+
+            if manifest.answers.insert((day, part, key.to_string()), answer).is_some() {
+                return syntax_error(line_no, &format!("Duplicate answer for day {} part {} {}", day, part, key));
+            }
+        }
+        return Ok(manifest);
+    }
+
+    pub fn load(path: &str) -> Result<Manifest, ManifestError> {
+        let text = fs::read_to_string(path).map_err(ManifestError::Io)?;
+        return Manifest::parse(&text.replace("\r", ""));
+    }
+
+    pub fn expected(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        return self.answers.get(&(day, part, input.to_string())).map(|s| s.as_str());
+    }
+
+    //
+    // Compares an answer to the recorded one. Text answers are compared
+    // without surrounding blank lines and trailing whitespace, so rendered
+    // letters survive editors stripping the manifest.
+    //
+    pub fn matches(expected: &str, answer: &Answer) -> bool {
+        fn normalize(text: &str) -> String {
+            let lines: Vec<&str> = text.lines().map(|l| l.trim_end()).collect();
+            return lines.join("\n").trim_matches('\n').to_string();
+        }
+        return match answer {
+            Answer::Text(text) => normalize(text) == normalize(expected),
+            _ => answer.to_string() == expected,
+        };
+    }
+}
+
+#[derive(Debug)]
+pub enum Verdict {
+    Pass,
+    Mismatch{expected: String, answer: Answer},
+    // The input could not be read or parsed
+    Failed(String),
+    // Solved, but there is no recorded answer to compare with
+    Unrecorded(Answer),
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+}
+
+impl Verification {
+    pub fn is_regression(&self) -> bool {
+        return matches!(self.verdict, Verdict::Mismatch{..} | Verdict::Failed(_));
+    }
+}
+
+//
+// Solves every part of the entries from the given input and compares the
+// answers with the manifest
+//
+pub fn verify(manifest: &Manifest, source: &InputSource, entries: &[&Entry]) -> Vec<Verification> {
+    let key = source.manifest_key().unwrap_or("");
+
+    let mut results = Vec::new();
+    for entry in entries {
+        let data = source.read(entry.day).map_err(|e| e.to_string());
+        for part in 1 ..= entry.parts {
+            let solved = data.clone().and_then(|data| (entry.solve)(&data, part).map_err(|e| e.to_string()));
+            let verdict = match (solved, manifest.expected(entry.day, part, key)) {
+                (Err(error), _) => Verdict::Failed(error),
+                (Ok(answer), None) => Verdict::Unrecorded(answer),
+                (Ok(answer), Some(expected)) if Manifest::matches(expected, &answer) => Verdict::Pass,
+                (Ok(answer), Some(expected)) => Verdict::Mismatch{expected: expected.to_string(), answer: answer},
+            };
+            results.push(Verification{day: entry.day, part: part, verdict: verdict});
+        }
+    }
+    return results;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_integers_and_strings() {
+        let manifest = Manifest::parse("[day1.part2] # comment\nsample = -5\npuzzle = \"a\\\"b\"\n\n[day13.part2]\npuzzle = \"\"\"\n# #\n ##\n\"\"\"").unwrap();
+        assert_eq!(manifest.expected(1, 2, "sample"), Some("-5"));
+        assert_eq!(manifest.expected(1, 2, "puzzle"), Some("a\"b"));
+        assert_eq!(manifest.expected(13, 2, "puzzle"), Some("# #\n ##\n"));
+        assert_eq!(manifest.expected(1, 1, "sample"), None);
+    }
+
+    #[test]
+    fn reports_the_line_of_errors() {
+        let error = Manifest::parse("[day1.part1]\nsample = 7\n\nsample = 8").unwrap_err();
+        assert!(matches!(error, ManifestError::Syntax{line: 4, ..}));
+        assert!(matches!(Manifest::parse("sample = 1").unwrap_err(), ManifestError::Syntax{line: 1, ..}));
+        assert!(matches!(Manifest::parse("[day1]").unwrap_err(), ManifestError::Syntax{line: 1, ..}));
+    }
+
+    #[test]
+    fn text_answers_ignore_trailing_whitespace() {
+        assert!(Manifest::matches("# #\n ##", &Answer::Text("\n# #  \n ## \n".to_string())));
+        assert!(!Manifest::matches("# #", &Answer::Text("## ".to_string())));
+        assert!(Manifest::matches("42", &Answer::UInt(42)));
+    }
+}
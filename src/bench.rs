@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use crate::solution::SolveError;
+use crate::registry::Entry;
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) { (sorted[mid - 1] + sorted[mid]) / 2 } else { sorted[mid] };
+        return Stats{min: sorted[0], median: median, max: sorted[sorted.len() - 1]};
+    }
+}
+
+#[derive(Debug)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+//
+// Solves the part runs times, every run parses the data again so both
+// phases are measured each time
+//
+pub fn run(entry: &Entry, data: &str, part: u8, runs: usize) -> Result<Benchmark, SolveError> {
+    assert!(runs > 0, "Benchmarks need at least one run");
+
+    let mut parse_times = Vec::new();
+    let mut solve_times = Vec::new();
+    for _run in 0 .. runs {
+        let (_answer, timings) = (entry.solve_timed)(data, part)?;
+        parse_times.push(timings.parse);
+        solve_times.push(timings.solve);
+    }
+    return Ok(Benchmark{day: entry.day, part: part, runs: runs,
+                        parse: Stats::new(&parse_times), solve: Stats::new(&solve_times)});
+}
+
+// Columns of the exported tables, all durations are in nanoseconds
+const COLUMNS: [&str; 9] = ["day", "part", "runs",
+                            "parse_min_ns", "parse_median_ns", "parse_max_ns",
+                            "solve_min_ns", "solve_median_ns", "solve_max_ns"];
+
+fn row(bench: &Benchmark) -> [u128; 9] {
+    return [bench.day as u128, bench.part as u128, bench.runs as u128,
+            bench.parse.min.as_nanos(), bench.parse.median.as_nanos(), bench.parse.max.as_nanos(),
+            bench.solve.min.as_nanos(), bench.solve.median.as_nanos(), bench.solve.max.as_nanos()];
+}
+
+pub fn to_csv(benches: &[Benchmark]) -> String {
+    let mut res = COLUMNS.join(",");
+    res.push('\n');
+    for bench in benches {
+        res.push_str(&row(bench).map(|v| v.to_string()).join(","));
+        res.push('\n');
+    }
+    return res;
+}
+
+pub fn to_json(benches: &[Benchmark]) -> String {
+    let records: Vec<String> = benches.iter().map(|bench| {
+        let fields: Vec<String> = COLUMNS.iter().zip(row(bench)).map(|(name, val)| format!("\"{}\": {}", name, val)).collect();
+        return format!("  {{{}}}", fields.join(", "));
+    }).collect();
+    return format!("[\n{}\n]\n", records.join(",\n"));
+}
+
+fn ms(duration: Duration) -> f64 {
+    return duration.as_secs_f64() * 1000.0;
+}
+
+pub fn to_table(benches: &[Benchmark]) -> String {
+    let mut res = format!("{:>3} {:>4} {:>4}   {:>28}   {:>28}\n", "day", "part", "runs",
+                          "parse ms (min/median/max)", "solve ms (min/median/max)");
+    for bench in benches {
+        res.push_str(&format!("{:>3} {:>4} {:>4}   {:>8.3} {:>9.3} {:>9.3}   {:>8.3} {:>9.3} {:>9.3}\n",
+                              bench.day, bench.part, bench.runs,
+                              ms(bench.parse.min), ms(bench.parse.median), ms(bench.parse.max),
+                              ms(bench.solve.min), ms(bench.solve.median), ms(bench.solve.max)));
+    }
+    return res;
+}
+
+// How fast some piece of code gets through a number of items
+#[derive(Debug)]
+pub struct Throughput {
+    pub name: String,
+    pub items: usize,
+    pub elapsed: Duration,
+}
+
+impl Throughput {
+    pub fn per_second(&self) -> f64 {
+        return self.items as f64 / self.elapsed.as_secs_f64();
+    }
+}
+
+pub fn throughput_table(results: &[Throughput], unit: &str) -> String {
+    let width = results.iter().map(|r| r.name.len()).max().unwrap_or(0).max(4);
+    let mut res = format!("{:<width$} {:>10} {:>10} {:>14}\n", "name", unit, "ms", format!("{}/s", unit), width = width);
+    for result in results {
+        res.push_str(&format!("{:<width$} {:>10} {:>10.3} {:>14.0}\n", result.name, result.items,
+                              ms(result.elapsed), result.per_second(), width = width));
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_take_the_middle_sample() {
+        let odd = Stats::new(&[5, 1, 3].map(Duration::from_millis));
+        assert_eq!((odd.min, odd.median, odd.max), (Duration::from_millis(1), Duration::from_millis(3), Duration::from_millis(5)));
+
+        let even = Stats::new(&[4, 1, 2, 8].map(Duration::from_millis));
+        assert_eq!(even.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn throughput_per_second() {
+        let result = Throughput{name: "alu".to_string(), items: 500, elapsed: Duration::from_millis(250)};
+        assert_eq!(result.per_second(), 2000.0);
+        assert_eq!(throughput_table(&[result], "inputs"), "name     inputs         ms       inputs/s\nalu         500    250.000           2000\n");
+    }
+}
@@ -0,0 +1,249 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Mul};
+use std::str::FromStr;
+
+/// An unsigned integer of any size
+#[derive(Debug)] #[derive(Clone)] #[derive(Default)] #[derive(Hash)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct BigUint {
+    // Base 2^32 digits, least significant first and without leading zeros,
+    // so 0 has none
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        return BigUint{limbs: Vec::new()};
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.limbs.is_empty();
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        return if self.limbs.len() <= 2 { Some(self.low_u64()) } else { None };
+    }
+
+    // The least significant 64 bits
+    pub fn low_u64(&self) -> u64 {
+        let limb = |i: usize| self.limbs.get(i).copied().unwrap_or(0) as u64;
+        return limb(0) | limb(1) << 32;
+    }
+
+    // Number of bits without leading zeros, 0 for 0
+    pub fn bits(&self) -> usize {
+        return match self.limbs.last() {
+            Some(top) => self.limbs.len() * 32 - top.leading_zeros() as usize,
+            None => 0,
+        };
+    }
+
+    // The n-th group of 4 bits, starting at the least significant
+    pub fn nibble(&self, n: usize) -> u8 {
+        let limb = self.limbs.get(n / 8).copied().unwrap_or(0);
+        return ((limb >> (n % 8 * 4)) & 0xF) as u8;
+    }
+
+    // self = self * factor + term
+    pub fn mul_add(&mut self, factor: u32, term: u32) {
+        let mut carry = term as u64;
+        for limb in self.limbs.iter_mut() {
+            let value = *limb as u64 * factor as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    // self = self / divisor, returning the remainder
+    fn div_rem(&mut self, divisor: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let value = rem << 32 | *limb as u64;
+            *limb = (value / divisor as u64) as u32;
+            rem = value % divisor as u64;
+        }
+        self.normalize();
+        return rem as u32;
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        let mut res = BigUint{limbs: vec![value as u32, (value >> 32) as u32]};
+        res.normalize();
+        return res;
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self.limbs.len().cmp(&other.limbs.len())
+                   .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()));
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+// Decimal, like the integer types
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Groups of 9 decimal digits, least significant first
+        let mut groups = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            groups.push(rest.div_rem(1_000_000_000));
+        }
+        let mut res = groups.pop().unwrap_or(0).to_string();
+        for group in groups.iter().rev() {
+            res.push_str(&format!("{:09}", group));
+        }
+        return f.pad_integral(true, "", &res);
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0;
+        for i in 0 .. self.limbs.len().max(other.limbs.len()) {
+            let sum = self.limbs.get(i).copied().unwrap_or(0) as u64 + other.limbs.get(i).copied().unwrap_or(0) as u64 + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+        let mut res = BigUint{limbs: limbs};
+        res.normalize();
+        return res;
+    }
+}
+
+// Schoolbook multiplication, the operands are not expected to get huge
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, b) in other.limbs.iter().enumerate() {
+                let value = *a as u64 * *b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = value as u32;
+                carry = value >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        let mut res = BigUint{limbs: limbs};
+        res.normalize();
+        return res;
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: BigUint) -> BigUint {
+        return &self + &other;
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: BigUint) -> BigUint {
+        return &self * &other;
+    }
+}
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        return iter.fold(BigUint::zero(), |sum, value| sum + value);
+    }
+}
+
+impl Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> BigUint {
+        return iter.fold(BigUint::from(1), |product, value| product * value);
+    }
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct ParseBigUintError;
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid decimal number")
+    }
+}
+
+impl std::error::Error for ParseBigUintError {}
+
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(s: &str) -> Result<BigUint, ParseBigUintError> {
+        if s.is_empty() {
+            return Err(ParseBigUintError);
+        }
+        let mut res = BigUint::zero();
+        for c in s.chars() {
+            res.mul_add(10, c.to_digit(10).ok_or(ParseBigUintError)?);
+        }
+        return Ok(res);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_round_trip() {
+        let big = "340282366920938463463374607431768211457";
+        assert_eq!(big.parse::<BigUint>().unwrap().to_string(), big);
+        assert_eq!("000120".parse::<BigUint>().unwrap(), BigUint::from(120));
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(format!("{:>5}", BigUint::from(42)), "   42");
+        assert!("12a".parse::<BigUint>().is_err());
+        assert!("".parse::<BigUint>().is_err());
+    }
+
+    #[test]
+    fn bits_and_ordering() {
+        let big: BigUint = "18446744073709551616".parse().unwrap();
+        assert_eq!((big.bits(), big.to_u64()), (65, None));
+        assert_eq!(BigUint::from(u64::MAX).to_u64(), Some(u64::MAX));
+        assert_eq!((BigUint::from(0xABC).nibble(1), BigUint::from(0xABC).nibble(9)), (0xB, 0));
+        assert!(BigUint::from(u64::MAX) < big);
+        assert!(BigUint::from(1 << 32) > BigUint::from(u32::MAX as u64));
+        assert!(BigUint::zero() < BigUint::from(1));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let max = BigUint::from(u64::MAX);
+        assert_eq!((&max + &BigUint::from(1)).to_string(), "18446744073709551616");
+        assert_eq!((&max * &max).to_string(), "340282366920938463426481119284349108225");
+        assert_eq!(&max * &BigUint::zero(), BigUint::zero());
+        let values = [3, 5, 7].map(BigUint::from);
+        assert_eq!(values.iter().cloned().sum::<BigUint>(), BigUint::from(15));
+        assert_eq!(values.iter().cloned().product::<BigUint>(), BigUint::from(105));
+        assert_eq!(Vec::<BigUint>::new().into_iter().product::<BigUint>(), BigUint::from(1));
+    }
+}
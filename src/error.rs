@@ -0,0 +1,89 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input, located by line and column within the input of a day
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct ParseError {
+    pub day: u8,
+    // Both start at 1, the column counts characters
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, line: usize, column: usize, token: &str, reason: &str) -> Self {
+        return ParseError{day: day, line: line, column: column, token: token.to_string(), reason: reason.to_string()};
+    }
+
+    //
+    // Builds the error for a token sliced out of the input data, the line and
+    // column are derived from where the slice sits within data. Tokens that
+    // are not part of data are reported at the end of the input.
+    //
+    pub fn at(day: u8, data: &str, token: &str, reason: &str) -> Self {
+        let offset = (token.as_ptr() as usize).checked_sub(data.as_ptr() as usize)
+                                              .filter(|offset| offset + token.len() <= data.len())
+                                              .unwrap_or(data.len());
+        let before = &data[.. offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start ..].chars().count() + 1;
+        return ParseError::new(day, line, column, token, reason);
+    }
+
+    // For input that is missing altogether rather than malformed
+    pub fn at_end(day: u8, data: &str, reason: &str) -> Self {
+        return ParseError::at(day, data, &data[data.len() ..], reason);
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} input, line {} column {}: {}", self.day, self.line, self.column, self.reason)?;
+        if !self.token.is_empty() {
+            write!(f, " at \"{}\"", self.token)?;
+        }
+        return Ok(());
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a number sliced out of data, the slice is reported as the offending token
+pub fn parse_number<T: FromStr>(day: u8, data: &str, token: &str) -> Result<T, ParseError> {
+    return token.parse::<T>().map_err(|_| ParseError::at(day, data, token, "Invalid number"));
+}
+
+/// The slice of line holding the character at the given byte index
+pub fn char_at(line: &str, index: usize) -> &str {
+    let len = line[index ..].chars().next().map(|c| c.len_utf8()).unwrap_or(0);
+    return &line[index .. index + len];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_token_within_data() {
+        let data = "12\n3x4\n5";
+        let error = parse_number::<i32>(1, data, &data[3 .. 6]).unwrap_err();
+        assert_eq!((error.line, error.column, error.token.as_str()), (2, 1, "3x4"));
+
+        let error = ParseError::at(1, data, char_at(&data[3 .. 6], 1), "Expected a digit");
+        assert_eq!((error.line, error.column, error.token.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn missing_tokens_are_reported_at_the_end() {
+        let foreign = String::from("56");
+        let error = ParseError::at(1, "12\n34", &foreign, "Missing");
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = ParseError::at_end(1, "12\n34", "Missing");
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
@@ -0,0 +1,230 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::{self, ParseError};
+
+pub type Pos = (i64, i64);
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)]
+pub enum Neighbourhood {
+    // Left, right, up and down
+    Orthogonal,
+    // Including the diagonals
+    All,
+}
+
+const ORTHOGONAL_OFFSETS: [Pos; 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const ALL_OFFSETS: [Pos; 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+impl Neighbourhood {
+    fn offsets(&self) -> &'static [Pos] {
+        return match self {
+            Neighbourhood::Orthogonal => &ORTHOGONAL_OFFSETS,
+            Neighbourhood::All => &ALL_OFFSETS,
+        };
+    }
+}
+
+//
+// A rectangular grid stored row by row, positions are (x, y) with y growing
+// downwards. A wrapping grid is toroidal: stepping off one edge enters at the
+// opposite one.
+//
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: i64,
+    height: i64,
+    wrapping: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: i64, height: i64, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        return Grid{cells: vec![fill; (width * height) as usize], width: width, height: height, wrapping: false};
+    }
+
+    // The cells are given row by row, so their count must be a multiple of width
+    pub fn from_cells(width: i64, cells: Vec<T>) -> Self {
+        assert!(width > 0 && cells.len() as i64 % width == 0, "{} cells do not fill rows of {}", cells.len(), width);
+        let height = cells.len() as i64 / width;
+        return Grid{cells: cells, width: width, height: height, wrapping: false};
+    }
+
+    //
+    // Parses a grid with a cell per character, every row must be equally
+    // long. Characters rejected by cell are reported with reason.
+    //
+    pub fn parse<F>(day: u8, data: &str, reason: &str, cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut width = 0;
+        let mut cells = Vec::new();
+        for line in data.lines() {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).ok_or_else(|| ParseError::at(day, data, error::char_at(line, i), reason))?);
+            }
+            let row_width = cells.len() - row_start;
+            if width == 0 {
+                width = row_width;
+            }
+            if row_width == 0 || row_width != width {
+                return Err(ParseError::at(day, data, line, &format!("Expected a row of {} cells", width.max(1))));
+            }
+        }
+        if cells.is_empty() {
+            return Err(ParseError::at_end(day, data, "Empty grid"));
+        }
+        return Ok(Grid::from_cells(width as i64, cells));
+    }
+
+    pub fn wrapping(mut self, wrapping: bool) -> Self {
+        self.wrapping = wrapping;
+        return self;
+    }
+
+    pub fn width(&self) -> i64 {
+        return self.width;
+    }
+
+    pub fn height(&self) -> i64 {
+        return self.height;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.cells.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.cells.is_empty();
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        return pos.0 >= 0 && pos.0 < self.width && pos.1 >= 0 && pos.1 < self.height;
+    }
+
+    //
+    // The position delta away from pos, wrapped around the edges for
+    // wrapping grids and None if it falls off the grid otherwise
+    //
+    pub fn offset(&self, pos: Pos, delta: Pos) -> Option<Pos> {
+        let moved = (pos.0 + delta.0, pos.1 + delta.1);
+        if self.wrapping {
+            return Some((moved.0.rem_euclid(self.width), moved.1.rem_euclid(self.height)));
+        }
+        return if self.contains(moved) { Some(moved) } else { None };
+    }
+
+    pub fn neighbours(&self, pos: Pos, neighbourhood: Neighbourhood) -> Vec<Pos> {
+        return neighbourhood.offsets().iter().filter_map(|delta| self.offset(pos, *delta)).collect();
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        return Some(&self.cells[(pos.1 * self.width + pos.0) as usize]);
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        return Some(&mut self.cells[(pos.1 * self.width + pos.0) as usize]);
+    }
+
+    // All positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        return (0 .. height).flat_map(move |y| (0 .. width).map(move |x| (x, y)));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        return self.cells.iter();
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        return self.cells.iter_mut();
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        return Grid{cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height, wrapping: self.wrapping};
+    }
+
+    // One line per row, each one ending in a newline
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let mut res = String::new();
+        for row in self.cells.chunks(self.width as usize) {
+            res.extend(row.iter().map(&f));
+            res.push('\n');
+        }
+        return res;
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        return self.get(pos).unwrap_or_else(|| panic!("{:?} is outside of the grid", pos));
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        return self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside of the grid", pos));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(data: &str) -> Result<Grid<u32>, ParseError> {
+        return Grid::parse(0, data, "Expected a digit", |c| c.to_digit(10));
+    }
+
+    #[test]
+    fn indexes_non_square_grids() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.render(|d| char::from_digit(*d, 10).unwrap()), "123\n456\n");
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        let grid = digits("123\n456\n789").unwrap();
+        assert_eq!(grid.neighbours((0, 0), Neighbourhood::Orthogonal), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours((1, 1), Neighbourhood::All).len(), 8);
+        assert_eq!(grid.neighbours((0, 0), Neighbourhood::All).len(), 3);
+
+        let torus = grid.wrapping(true);
+        assert_eq!(torus.neighbours((0, 0), Neighbourhood::Orthogonal), [(2, 0), (1, 0), (0, 2), (0, 1)]);
+        assert_eq!(torus.offset((2, 2), (1, 1)), Some((0, 0)));
+    }
+
+    #[test]
+    fn rejects_ragged_and_unknown_cells() {
+        let error = digits("12\n345").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = digits("12\n3x").unwrap_err();
+        assert_eq!((error.line, error.column, error.token.as_str()), (2, 2, "x"));
+        assert!(digits("").is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::PathBuf;
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)]
+pub enum InputSource {
+    // The DayN/input file next to the solution
+    Puzzle,
+    // The DayN/sample_input file with the example from the puzzle statement
+    Sample,
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    pub source: String,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Could not read input for day {} from {}: {}", self.day, self.source, self.error)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl InputSource {
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        return match self {
+            InputSource::Puzzle => Some(PathBuf::from(format!("Day{}/input", day))),
+            InputSource::Sample => Some(PathBuf::from(format!("Day{}/sample_input", day))),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        };
+    }
+
+    // The key of the input in the answers manifest, only the inputs shipped with each day have one
+    pub fn manifest_key(&self) -> Option<&'static str> {
+        return match self {
+            InputSource::Puzzle => Some("puzzle"),
+            InputSource::Sample => Some("sample"),
+            _ => None,
+        };
+    }
+
+    pub fn describe(&self, day: u8) -> String {
+        return match self.path(day) {
+            Some(path) => format!("\"{}\"", path.display()),
+            None => "stdin".to_string(),
+        };
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let read = match self.path(day) {
+            Some(path) => fs::read_to_string(path),
+            None => {
+                let mut data = String::new();
+                io::stdin().read_to_string(&mut data).map(|_| data)
+            }
+        };
+
+        return match read {
+            Ok(data) => Ok(normalize(&data)),
+            Err(error) => Err(InputError{day: day, source: self.describe(day), error: error}),
+        };
+    }
+}
+
+// The parsers expect unix line endings and no trailing newline
+fn normalize(data: &str) -> String {
+    return data.replace("\r", "").trim_end_matches('\n').to_string();
+}
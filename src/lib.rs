@@ -0,0 +1,65 @@
+// Explicit returns and field names are the house style
+#![allow(clippy::needless_return)]
+#![allow(clippy::redundant_field_names)]
+
+pub mod error;
+pub mod solution;
+pub mod registry;
+pub mod input;
+pub mod answers;
+pub mod bench;
+pub mod json;
+pub mod grid;
+pub mod search;
+pub mod bignum;
+
+#[path = "../Day1/mod.rs"]
+pub mod day1;
+#[path = "../Day2/mod.rs"]
+pub mod day2;
+#[path = "../Day3/mod.rs"]
+pub mod day3;
+#[path = "../Day4/mod.rs"]
+pub mod day4;
+#[path = "../Day5/mod.rs"]
+pub mod day5;
+#[path = "../Day6/mod.rs"]
+pub mod day6;
+#[path = "../Day7/mod.rs"]
+pub mod day7;
+#[path = "../Day8/mod.rs"]
+pub mod day8;
+#[path = "../Day9/mod.rs"]
+pub mod day9;
+#[path = "../Day10/mod.rs"]
+pub mod day10;
+#[path = "../Day11/mod.rs"]
+pub mod day11;
+#[path = "../Day12/mod.rs"]
+pub mod day12;
+#[path = "../Day13/mod.rs"]
+pub mod day13;
+#[path = "../Day14/mod.rs"]
+pub mod day14;
+#[path = "../Day15/mod.rs"]
+pub mod day15;
+#[path = "../Day16/mod.rs"]
+pub mod day16;
+#[path = "../Day17/mod.rs"]
+pub mod day17;
+#[path = "../Day18/mod.rs"]
+pub mod day18;
+#[path = "../Day19/mod.rs"]
+pub mod day19;
+#[path = "../Day20/mod.rs"]
+pub mod day20;
+#[path = "../Day21/mod.rs"]
+pub mod day21;
+#[path = "../Day22/mod.rs"]
+pub mod day22;
+#[path = "../Day23/mod.rs"]
+pub mod day23;
+#[path = "../Day24/mod.rs"]
+pub mod day24;
+#[path = "../Day25/mod.rs"]
+pub mod day25;
@@ -0,0 +1,472 @@
+// Explicit returns are the house style
+#![allow(clippy::needless_return)]
+
+use std::env;
+use std::fs;
+use std::process;
+use std::path::PathBuf;
+
+use aoc_2021_solutions::registry;
+use aoc_2021_solutions::input::InputSource;
+use aoc_2021_solutions::answers::{self, Manifest, Verdict};
+use aoc_2021_solutions::bench;
+use aoc_2021_solutions::day18::{self, Day18, Snailfish, SnailfishNumber};
+use aoc_2021_solutions::day24::{self, Day24, Variable};
+use aoc_2021_solutions::day24::debugger::{self, Breakpoint, Debugger, Stop};
+use aoc_2021_solutions::solution::Solution;
+
+const USAGE: &str = "Usage:
+    aoc run <day> <part> [input] [--format text|json]
+    aoc run <day> [input] [--format text|json]
+    aoc run --all [--sample] [--format text|json]
+    aoc verify [<day>] [--sample] [--answers <path>]
+    aoc bench [<day> [<part>]] [input] [--runs <n>] [--format table|json|csv]
+    aoc bench-alu [input] [--inputs <n>]
+    aoc bench-snailfish [input] [--rounds <n>]
+    aoc add-snailfish [<number>...] [input] [--trace]
+    aoc debug-alu <model number> [input] [--break <index>|inp|inp<n>] [--watch x|y|z|w]
+                  [--trace <path>] [--format csv|json]
+    aoc list
+
+Input:
+    --input <path>  Read the puzzle input from a file
+    --sample        Use the DayN/sample_input file
+    -               Read the puzzle input from stdin
+    By default the DayN/input file is used
+
+The json format prints one {day, part, answer, elapsed} record per line,
+with elapsed in seconds. Diagnostics are always written to stderr.
+
+Verify compares the answers with the ones recorded in answers.toml and
+exits with status 1 on mismatches or failures
+
+Bench times parsing and solving separately over 5 runs by default,
+bench-alu runs 100000 model numbers through the Day 24 program on each
+of the ALU executors. bench-snailfish times the Day 18 part 2 search on the
+boxed and the flat snailfish numbers, 3 rounds each by default.
+
+Add-snailfish prints the sum of the snailfish numbers given, or of the Day 18
+input if there are none, and its magnitude. With --trace every addition is
+followed by the explosions and splits reducing it, each with the path of the
+pair or number from the outermost pair (L and R) and the number it leaves.
+
+Debug-alu runs the Day 24 program on the digits of the model number and
+prints the registers at every breakpoint and change of a watched register.
+Breakpoints stop before the instruction at an index, before every input or
+before reading the n-th input. The trace of every executed instruction is
+written to the --trace file as csv (the default) or json";
+
+fn parse_number(s: &str, what: &str) -> Result<u8, String> {
+    return s.parse::<u8>().map_err(|_| format!("Invalid {}: \"{}\"", what, s));
+}
+
+//
+// Splits the input selection from the other arguments
+//
+fn parse_source(args: &[String]) -> Result<(InputSource, Vec<String>), String> {
+    let mut source = InputSource::Puzzle;
+    let mut rest = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = iter.next().ok_or("--input needs a path")?;
+                source = if path == "-" { InputSource::Stdin } else { InputSource::File(PathBuf::from(path)) };
+            },
+            "--sample" => source = InputSource::Sample,
+            "-" => source = InputSource::Stdin,
+            _ => rest.push(arg.clone()),
+        }
+    }
+    return Ok((source, rest));
+}
+
+#[derive(Clone)] #[derive(Copy)] #[derive(PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn run_entry(entry: &registry::Entry, parts: &[u8], source: &InputSource, format: Format) -> Result<(), String> {
+    let data = source.read(entry.day).map_err(|e| e.to_string())?;
+    for part in parts {
+        let (answer, timings) = (entry.solve_timed)(&data, *part).map_err(|e| e.to_string())?;
+        match format {
+            Format::Text => println!("Day {} Part {}: {}", entry.day, part, answer),
+            Format::Json => println!("{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed\": {}}}",
+                                     entry.day, part, answer.to_json(), (timings.parse + timings.solve).as_secs_f64()),
+        }
+    }
+    return Ok(());
+}
+
+fn all_parts(entry: &registry::Entry) -> Vec<u8> {
+    return (1 ..= entry.parts).collect();
+}
+
+fn find_entry(day: &str) -> Result<&'static registry::Entry, String> {
+    let day = parse_number(day, "day")?;
+    return registry::find(day).ok_or(format!("No solutions for day {}", day));
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (source, args) = parse_source(args)?;
+
+    let mut format = Format::Text;
+    let mut positional = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--format" {
+            format = match iter.next().map(|s| s.as_str()) {
+                Some("text") => Format::Text,
+                Some("json") => Format::Json,
+                _ => return Err("--format needs text or json".to_string()),
+            };
+        } else {
+            positional.push(arg.clone());
+        }
+    }
+
+    match positional.as_slice() {
+        [flag] if flag == "--all" => {
+            if !matches!(source, InputSource::Puzzle | InputSource::Sample) {
+                return Err("A single input can only be used when running one day".to_string());
+            }
+            for entry in registry::SOLUTIONS.iter() {
+                run_entry(entry, &all_parts(entry), &source, format)?;
+            }
+        },
+        [day] => {
+            let entry = find_entry(day)?;
+            run_entry(entry, &all_parts(entry), &source, format)?;
+        },
+        [day, part] => {
+            let entry = find_entry(day)?;
+            let part = parse_number(part, "part")?;
+            if part < 1 || part > entry.parts {
+                return Err(format!("No solution for day {} part {}", entry.day, part));
+            }
+            run_entry(entry, &[part], &source, format)?;
+        },
+        _ => return Err(USAGE.to_string()),
+    }
+    return Ok(());
+}
+
+//
+// Returns whether every answer matched or had nothing to compare with
+//
+fn verify(args: &[String]) -> Result<bool, String> {
+    let (source, args) = parse_source(args)?;
+    if source.manifest_key().is_none() {
+        return Err("Only the puzzle and sample inputs have recorded answers".to_string());
+    }
+
+    let mut manifest_path = answers::DEFAULT_PATH.to_string();
+    let mut entries: Vec<&registry::Entry> = registry::SOLUTIONS.iter().collect();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--answers" {
+            manifest_path = iter.next().ok_or("--answers needs a path")?.clone();
+        } else {
+            entries = vec![find_entry(arg)?];
+        }
+    }
+    let manifest = Manifest::load(&manifest_path).map_err(|e| e.to_string())?;
+
+    let results = answers::verify(&manifest, &source, &entries);
+    let (mut passed, mut mismatched, mut failed, mut unrecorded) = (0, 0, 0, 0);
+    for result in &results {
+        let status = match &result.verdict {
+            Verdict::Pass => {
+                passed += 1;
+                "pass".to_string()
+            },
+            Verdict::Mismatch{expected, answer} => {
+                mismatched += 1;
+                format!("MISMATCH expected {}, got {}", expected, answer)
+            },
+            Verdict::Failed(error) => {
+                failed += 1;
+                format!("FAIL {}", error)
+            },
+            Verdict::Unrecorded(answer) => {
+                unrecorded += 1;
+                format!("no recorded answer, got {}", answer)
+            },
+        };
+        println!("day {:>2} part {}: {}", result.day, result.part, status);
+    }
+    println!("{} passed, {} mismatched, {} failed, {} without answer", passed, mismatched, failed, unrecorded);
+
+    return Ok(!results.iter().any(|r| r.is_regression()));
+}
+
+const DEFAULT_BENCH_RUNS: usize = 5;
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let (source, args) = parse_source(args)?;
+
+    let mut runs = DEFAULT_BENCH_RUNS;
+    let mut render: fn(&[bench::Benchmark]) -> String = bench::to_table;
+    let mut positional = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--runs" => {
+                let count = iter.next().ok_or("--runs needs a count")?;
+                runs = count.parse::<usize>().ok().filter(|r| *r > 0)
+                                             .ok_or(format!("Invalid run count: \"{}\"", count))?;
+            },
+            "--format" => {
+                render = match iter.next().map(|s| s.as_str()) {
+                    Some("table") => bench::to_table,
+                    Some("json") => bench::to_json,
+                    Some("csv") => bench::to_csv,
+                    _ => return Err("--format needs table, json or csv".to_string()),
+                };
+            },
+            _ => positional.push(arg),
+        }
+    }
+
+    let targets: Vec<(&registry::Entry, Vec<u8>)> = match positional.as_slice() {
+        [] => registry::SOLUTIONS.iter().map(|e| (e, all_parts(e))).collect(),
+        [day] => {
+            let entry = find_entry(day)?;
+            vec![(entry, all_parts(entry))]
+        },
+        [day, part] => {
+            let entry = find_entry(day)?;
+            let part = parse_number(part, "part")?;
+            if part < 1 || part > entry.parts {
+                return Err(format!("No solution for day {} part {}", entry.day, part));
+            }
+            vec![(entry, vec![part])]
+        },
+        _ => return Err(USAGE.to_string()),
+    };
+    if targets.len() > 1 && !matches!(source, InputSource::Puzzle | InputSource::Sample) {
+        return Err("A single input can only be used when benchmarking one day".to_string());
+    }
+
+    let mut results = Vec::new();
+    for (entry, parts) in targets {
+        let data = source.read(entry.day).map_err(|e| e.to_string())?;
+        for part in parts {
+            results.push(bench::run(entry, &data, part, runs).map_err(|e| e.to_string())?);
+        }
+    }
+
+    print!("{}", render(&results));
+    return Ok(());
+}
+
+const DEFAULT_ALU_INPUTS: usize = 100000;
+
+fn bench_alu(args: &[String]) -> Result<(), String> {
+    let (source, args) = parse_source(args)?;
+
+    let mut inputs = DEFAULT_ALU_INPUTS;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--inputs" => {
+                let count = iter.next().ok_or("--inputs needs a count")?;
+                inputs = count.parse::<usize>().ok().filter(|c| *c > 0)
+                                               .ok_or(format!("Invalid input count: \"{}\"", count))?;
+            },
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let data = source.read(24).map_err(|e| e.to_string())?;
+    let program = Day24::parse(&data).map_err(|e| e.to_string())?;
+    let results = day24::compiled::benchmark(&program, inputs).map_err(|e| format!("The program faults: {}", e))?;
+    print!("{}", bench::throughput_table(&results, "inputs"));
+    return Ok(());
+}
+
+const DEFAULT_SNAILFISH_ROUNDS: usize = 3;
+
+fn bench_snailfish(args: &[String]) -> Result<(), String> {
+    let (source, args) = parse_source(args)?;
+
+    let mut rounds = DEFAULT_SNAILFISH_ROUNDS;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--rounds" => {
+                let count = iter.next().ok_or("--rounds needs a count")?;
+                rounds = count.parse::<usize>().ok().filter(|c| *c > 0)
+                                               .ok_or(format!("Invalid round count: \"{}\"", count))?;
+            },
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let data = source.read(18).map_err(|e| e.to_string())?;
+    let numbers = Day18::parse(&data).map_err(|e| e.to_string())?;
+    print!("{}", bench::throughput_table(&day18::flat::benchmark(&numbers, rounds), "sums"));
+    return Ok(());
+}
+
+fn add_snailfish(args: &[String]) -> Result<(), String> {
+    let (source, args) = parse_source(args)?;
+
+    let mut numbers = Vec::new();
+    let mut trace = false;
+    for arg in &args {
+        match arg.as_str() {
+            "--trace" => trace = true,
+            number if number.starts_with('[') => {
+                numbers.push(number.parse::<SnailfishNumber>().map_err(|e| format!("Invalid snailfish number \"{}\": {}", number, e))?);
+            },
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    if numbers.is_empty() {
+        let data = source.read(18).map_err(|e| e.to_string())?;
+        numbers = Day18::parse(&data).map_err(|e| e.to_string())?;
+    }
+
+    let mut iter = numbers.iter();
+    let mut sum = iter.next().ok_or("No snailfish numbers to add")?.clone();
+    // Like Sum, the first number is reduced on its own
+    if trace {
+        for step in sum.reduce_traced() {
+            println!("{}: {}", step.action, step.number);
+        }
+    } else {
+        sum.reduce_in_place();
+    }
+    for number in iter {
+        if !trace {
+            sum = &sum + number;
+            continue;
+        }
+        println!("  {}\n+ {}", sum, number);
+        let (next, steps) = sum.add_traced(number);
+        for step in steps {
+            println!("{}: {}", step.action, step.number);
+        }
+        println!("= {}", next);
+        sum = next;
+    }
+    println!("Sum: {}", sum);
+    println!("Magnitude: {}", sum.magnitude());
+    return Ok(());
+}
+
+fn parse_breakpoint(s: &str) -> Result<Breakpoint, String> {
+    let invalid = || format!("Invalid breakpoint: \"{}\"", s);
+    return match s.strip_prefix("inp") {
+        Some("") => Ok(Breakpoint::AnyInput),
+        Some(n) => n.parse::<usize>().ok().filter(|n| *n > 0).map(Breakpoint::Input).ok_or_else(invalid),
+        None => s.parse::<usize>().map(Breakpoint::Instruction).map_err(|_| invalid()),
+    };
+}
+
+fn debug_alu(args: &[String]) -> Result<(), String> {
+    let (source, args) = parse_source(args)?;
+
+    let mut digits = None;
+    let mut breakpoints = Vec::new();
+    let mut watches = Vec::new();
+    let mut trace_path = None;
+    let mut render: fn(&[debugger::TraceEntry]) -> String = debugger::trace_to_csv;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--break" => breakpoints.push(parse_breakpoint(iter.next().ok_or("--break needs an index or inp")?)?),
+            "--watch" => watches.push(match iter.next().map(|s| s.as_str()) {
+                Some("x") => Variable::X,
+                Some("y") => Variable::Y,
+                Some("z") => Variable::Z,
+                Some("w") => Variable::W,
+                _ => return Err("--watch needs x, y, z or w".to_string()),
+            }),
+            "--trace" => trace_path = Some(iter.next().ok_or("--trace needs a path")?.clone()),
+            "--format" => {
+                render = match iter.next().map(|s| s.as_str()) {
+                    Some("csv") => debugger::trace_to_csv,
+                    Some("json") => debugger::trace_to_json,
+                    _ => return Err("--format needs csv or json".to_string()),
+                };
+            },
+            number if digits.is_none() => {
+                let parsed: Option<Vec<i64>> = number.chars().map(|c| c.to_digit(10).map(|d| d as i64)).collect();
+                digits = Some(parsed.ok_or(format!("Invalid model number: \"{}\"", number))?);
+            },
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let digits = digits.ok_or(USAGE.to_string())?;
+
+    let data = source.read(24).map_err(|e| e.to_string())?;
+    let program = Day24::parse(&data).map_err(|e| e.to_string())?;
+    let mut debugger = Debugger::new(&program, &digits);
+    for breakpoint in breakpoints {
+        debugger.add_breakpoint(breakpoint);
+    }
+    for var in watches {
+        debugger.watch(var);
+    }
+
+    let stop = loop {
+        let stop = debugger.resume();
+        let registers = debugger.registers();
+        let next = match debugger.next_instruction() {
+            Some(instruction) => format!("next {} \"{}\"", debugger.position(), instruction),
+            None => "end".to_string(),
+        };
+        println!("{}, {}: x={} y={} z={} w={}", stop, next, registers[0], registers[1], registers[2], registers[3]);
+        if matches!(stop, Stop::Finished | Stop::Fault(_)) {
+            break stop;
+        }
+    };
+
+    // The trace up to a fault is written as well
+    if let Some(path) = trace_path {
+        fs::write(&path, render(debugger.trace())).map_err(|e| format!("Could not write \"{}\": {}", path, e))?;
+    }
+    if let Stop::Fault(_) = stop {
+        return Err(stop.to_string());
+    }
+    return Ok(());
+}
+
+fn list() {
+    for entry in registry::SOLUTIONS.iter() {
+        for part in all_parts(entry) {
+            println!("day {:>2} part {}", entry.day, part);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    // Whether the command succeeded, only verify can fail without an error
+    let res = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]).map(|_| true),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]).map(|_| true),
+        Some("bench-alu") => bench_alu(&args[1..]).map(|_| true),
+        Some("bench-snailfish") => bench_snailfish(&args[1..]).map(|_| true),
+        Some("add-snailfish") => add_snailfish(&args[1..]).map(|_| true),
+        Some("debug-alu") => debug_alu(&args[1..]).map(|_| true),
+        Some("list") => { list(); Ok(true) },
+        _ => Err(USAGE.to_string()),
+    };
+
+    match res {
+        Ok(true) => {},
+        Ok(false) => process::exit(1),
+        Err(msg) => {
+            eprintln!("{}", msg);
+            process::exit(2);
+        },
+    }
+}
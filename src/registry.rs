@@ -0,0 +1,49 @@
+use crate::solution::{self, Answer, Solution, SolveError, Timings};
+use crate::{day1, day2, day3, day4, day5, day6, day7, day8, day9,
+            day10, day11, day12, day13, day14, day15, day16, day17, day18,
+            day19, day20, day21, day22, day23, day24, day25};
+
+pub type TimedSolveFn = fn(&str, u8) -> Result<(Answer, Timings), SolveError>;
+
+pub struct Entry {
+    pub day: u8,
+    pub parts: u8,
+    pub solve: fn(&str, u8) -> Result<Answer, SolveError>,
+    pub solve_timed: TimedSolveFn,
+}
+
+const fn entry<S: Solution>(day: u8) -> Entry {
+    return Entry{day: day, parts: S::PARTS, solve: solution::solve::<S>, solve_timed: solution::solve_timed::<S>};
+}
+
+pub const SOLUTIONS: [Entry; 25] = [
+    entry::<day1::Day1>(1),
+    entry::<day2::Day2>(2),
+    entry::<day3::Day3>(3),
+    entry::<day4::Day4>(4),
+    entry::<day5::Day5>(5),
+    entry::<day6::Day6>(6),
+    entry::<day7::Day7>(7),
+    entry::<day8::Day8>(8),
+    entry::<day9::Day9>(9),
+    entry::<day10::Day10>(10),
+    entry::<day11::Day11>(11),
+    entry::<day12::Day12>(12),
+    entry::<day13::Day13>(13),
+    entry::<day14::Day14>(14),
+    entry::<day15::Day15>(15),
+    entry::<day16::Day16>(16),
+    entry::<day17::Day17>(17),
+    entry::<day18::Day18>(18),
+    entry::<day19::Day19>(19),
+    entry::<day20::Day20>(20),
+    entry::<day21::Day21>(21),
+    entry::<day22::Day22>(22),
+    entry::<day23::Day23>(23),
+    entry::<day24::Day24>(24),
+    entry::<day25::Day25>(25),
+];
+
+pub fn find(day: u8) -> Option<&'static Entry> {
+    return SOLUTIONS.iter().find(|e| e.day == day);
+}
@@ -0,0 +1,249 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+//
+// A problem to search: the states reachable from a state together with the
+// cost of each move, and the states that end the search. Costs must not be
+// negative.
+//
+pub trait Problem {
+    type State: Clone;
+    // States with equal keys are the same to the search and expanded once
+    type Key: Hash + Eq;
+
+    fn key(&self, state: &Self::State) -> Self::Key;
+
+    fn neighbours(&self, state: &Self::State) -> Vec<(Self::State, i64)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    //
+    // Estimate of the cost left to reach a goal. A* only finds the cheapest
+    // path if the estimate never exceeds the actual cost. Unless it is also
+    // consistent, dropping by no more than the cost of each move, states
+    // can be reached more cheaply after they were expanded and are expanded
+    // again.
+    //
+    fn heuristic(&self, _state: &Self::State) -> i64 {
+        return 0;
+    }
+}
+
+#[derive(Debug)] #[derive(Clone)]
+pub struct Path<S> {
+    // From the start to the goal, every state with the total cost to reach it
+    pub steps: Vec<(S, i64)>,
+    pub cost: i64,
+}
+
+impl<S> Path<S> {
+    pub fn start(&self) -> &S {
+        return &self.steps[0].0;
+    }
+
+    pub fn goal(&self) -> &S {
+        return &self.steps[self.steps.len() - 1].0;
+    }
+
+    pub fn states(&self) -> impl Iterator<Item = &S> {
+        return self.steps.iter().map(|(state, _)| state);
+    }
+}
+
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)] #[derive(Default)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct Stats {
+    // States taken off the queue and expanded, a state reached more cheaply later counts again
+    pub expanded: usize,
+    // States put on the queue, including the start
+    pub pushed: usize,
+    // States taken off the queue after a cheaper copy was queued
+    pub skipped: usize,
+}
+
+#[derive(Debug)]
+pub struct Search<S> {
+    // None if no goal can be reached
+    pub path: Option<Path<S>>,
+    pub stats: Stats,
+}
+
+struct Node<S> {
+    state: S,
+    parent: Option<usize>,
+    cost: i64,
+}
+
+/// Cheapest path from start to a goal, ignoring the heuristic of the problem
+pub fn dijkstra<P: Problem>(problem: &P, start: P::State) -> Search<P::State> {
+    return search(problem, start, false);
+}
+
+/// Cheapest path from start to a goal, guided by the heuristic of the problem
+pub fn astar<P: Problem>(problem: &P, start: P::State) -> Search<P::State> {
+    return search(problem, start, true);
+}
+
+//
+// Every state reached is kept in nodes, the queue orders their indices by
+// cost plus estimate (ties go to the older node) and the parent indices give
+// the path back once a goal is taken off the queue. A state is only queued if
+// it is cheaper than any earlier copy, copies that got undercut are skipped.
+//
+fn search<P: Problem>(problem: &P, start: P::State, use_heuristic: bool) -> Search<P::State> {
+    let estimate = |state: &P::State| if use_heuristic { problem.heuristic(state) } else { 0 };
+
+    let mut stats = Stats::default();
+    let mut nodes: Vec<Node<P::State>> = Vec::new();
+    let mut queue: BinaryHeap<Reverse<(i64, usize)>> = BinaryHeap::new();
+    let mut cheapest: HashMap<P::Key, i64> = HashMap::new();
+
+    cheapest.insert(problem.key(&start), 0);
+    queue.push(Reverse((estimate(&start), 0)));
+    nodes.push(Node{state: start, parent: None, cost: 0});
+    stats.pushed += 1;
+
+    while let Some(Reverse((_, index))) = queue.pop() {
+        let cost = nodes[index].cost;
+        if cheapest[&problem.key(&nodes[index].state)] < cost {
+            stats.skipped += 1;
+            continue;
+        }
+        if problem.is_goal(&nodes[index].state) {
+            return Search{path: Some(reconstruct(&nodes, index)), stats: stats};
+        }
+        stats.expanded += 1;
+
+        for (next, step_cost) in problem.neighbours(&nodes[index].state) {
+            let next_cost = cost + step_cost;
+            let next_key = problem.key(&next);
+            if cheapest.get(&next_key).is_some_and(|c| *c <= next_cost) {
+                continue;
+            }
+            cheapest.insert(next_key, next_cost);
+            queue.push(Reverse((next_cost + estimate(&next), nodes.len())));
+            nodes.push(Node{state: next, parent: Some(index), cost: next_cost});
+            stats.pushed += 1;
+        }
+    }
+    return Search{path: None, stats: stats};
+}
+
+fn reconstruct<S: Clone>(nodes: &[Node<S>], goal: usize) -> Path<S> {
+    let mut steps = Vec::new();
+    let mut current = Some(goal);
+    while let Some(index) = current {
+        steps.push((nodes[index].state.clone(), nodes[index].cost));
+        current = nodes[index].parent;
+    }
+    steps.reverse();
+    return Path{steps: steps, cost: nodes[goal].cost};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Walking along a line of cells with the given cost of entering each one
+    struct Line {
+        costs: Vec<i64>,
+        goal: usize,
+    }
+
+    impl Problem for Line {
+        type State = usize;
+        type Key = usize;
+
+        fn key(&self, state: &usize) -> usize {
+            return *state;
+        }
+
+        fn neighbours(&self, state: &usize) -> Vec<(usize, i64)> {
+            let mut nexts = Vec::new();
+            if *state > 0 {
+                nexts.push((state - 1, self.costs[state - 1]));
+            }
+            if state + 1 < self.costs.len() {
+                nexts.push((state + 1, self.costs[state + 1]));
+            }
+            return nexts;
+        }
+
+        fn is_goal(&self, state: &usize) -> bool {
+            return *state == self.goal;
+        }
+
+        fn heuristic(&self, state: &usize) -> i64 {
+            return state.abs_diff(self.goal) as i64;
+        }
+    }
+
+    #[test]
+    fn reconstructs_the_cheapest_path() {
+        let line = Line{costs: vec![1, 5, 2, 1, 3], goal: 3};
+        let found = dijkstra(&line, 0).path.unwrap();
+        assert_eq!(found.cost, 8);
+        assert_eq!(found.steps, [(0, 0), (1, 5), (2, 7), (3, 8)]);
+        assert_eq!((*found.start(), *found.goal()), (0, 3));
+        assert_eq!(found.states().count(), 4);
+    }
+
+    #[test]
+    fn heuristic_saves_expansions() {
+        let line = Line{costs: vec![1; 20], goal: 10};
+        let plain = dijkstra(&line, 5);
+        let guided = astar(&line, 5);
+        assert_eq!(plain.path.unwrap().cost, 5);
+        assert_eq!(guided.path.unwrap().cost, 5);
+        assert_eq!(guided.stats.expanded, 5);
+        assert!(plain.stats.expanded > guided.stats.expanded);
+    }
+
+    // Moves between named states, with an estimate for each
+    struct Graph {
+        edges: Vec<(char, char, i64)>,
+        estimates: Vec<(char, i64)>,
+    }
+
+    impl Problem for Graph {
+        type State = char;
+        type Key = char;
+
+        fn key(&self, state: &char) -> char {
+            return *state;
+        }
+
+        fn neighbours(&self, state: &char) -> Vec<(char, i64)> {
+            return self.edges.iter().filter(|(from, _, _)| from == state).map(|(_, to, cost)| (*to, *cost)).collect();
+        }
+
+        fn is_goal(&self, state: &char) -> bool {
+            return *state == 'G';
+        }
+
+        fn heuristic(&self, state: &char) -> i64 {
+            return self.estimates.iter().find(|(s, _)| s == state).map_or(0, |(_, estimate)| *estimate);
+        }
+    }
+
+    #[test]
+    fn inconsistent_heuristic() {
+        // The estimate of A is exact but drops by 4 on the move of 1 to C, so
+        // C is first expanded the expensive way and has to be expanded again
+        let graph = Graph{edges: vec![('S', 'A', 1), ('A', 'C', 1), ('S', 'C', 3), ('C', 'G', 3)],
+                          estimates: vec![('A', 4)]};
+        let search = astar(&graph, 'S');
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states().collect::<String>(), "SACG");
+        assert_eq!(search.stats, Stats{expanded: 4, pushed: 6, skipped: 0});
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let line = Line{costs: vec![1, 1, 1], goal: 7};
+        let search = astar(&line, 0);
+        assert!(search.path.is_none());
+        assert_eq!(search.stats.expanded, 3);
+    }
+}
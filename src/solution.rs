@@ -0,0 +1,171 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+use crate::json;
+
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(val) => write!(f, "{}", val),
+            Answer::UInt(val) => write!(f, "{}", val),
+            Answer::Text(val) => write!(f, "{}", val),
+        }
+    }
+}
+
+impl Answer {
+    // Numbers stay numbers, text answers become strings
+    pub fn to_json(&self) -> String {
+        return match self {
+            Answer::Text(val) => json::string(val),
+            _ => self.to_string(),
+        };
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(val: i32) -> Self {
+        return Answer::Int(val as i64);
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(val: i64) -> Self {
+        return Answer::Int(val);
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(val: u32) -> Self {
+        return Answer::UInt(val as u64);
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(val: u64) -> Self {
+        return Answer::UInt(val);
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(val: usize) -> Self {
+        return Answer::UInt(val as u64);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(val: String) -> Self {
+        return Answer::Text(val);
+    }
+}
+
+/// Why an input that parsed has no answer, e.g. a program that accepts no model number
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub struct NoAnswer {
+    pub day: u8,
+    pub reason: String,
+}
+
+impl NoAnswer {
+    pub fn new(day: u8, reason: &str) -> Self {
+        return NoAnswer{day: day, reason: reason.to_string()};
+    }
+}
+
+impl fmt::Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "Day {} has no answer: {}", self.day, self.reason);
+    }
+}
+
+impl std::error::Error for NoAnswer {}
+
+/// Why solving a part failed, either the input is malformed or it has no answer
+#[derive(Debug)] #[derive(Clone)] #[derive(PartialEq)] #[derive(Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    NoAnswer{part: u8, error: NoAnswer},
+    // The part is not one of the 1 to parts of the day
+    NoSuchPart{part: u8, parts: u8},
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::NoAnswer{part, error} => write!(f, "Day {} part {} has no answer: {}", error.day, part, error.reason),
+            SolveError::NoSuchPart{part, parts} => write!(f, "There is no part {}, the day has {}", part, parts),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        return SolveError::Parse(e);
+    }
+}
+
+/// A single day of the calendar: the puzzle input is parsed once and both parts
+/// are solved from the parsed form.
+pub trait Solution {
+    type Input;
+
+    /// Number of parts the day has, the final day only has one
+    const PARTS: u8 = 2;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Answer, NoAnswer>;
+    fn part2(input: &Self::Input) -> Result<Answer, NoAnswer>;
+}
+
+/// Time spent in each phase of solving a part
+#[derive(Debug)] #[derive(Clone)] #[derive(Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Parses the data and solves the given part, parts beyond S::PARTS are an error
+pub fn solve<S: Solution>(data: &str, part: u8) -> Result<Answer, SolveError> {
+    return solve_timed::<S>(data, part).map(|(answer, _timings)| answer);
+}
+
+/// Like solve, also measuring how long parsing and solving took
+pub fn solve_timed<S: Solution>(data: &str, part: u8) -> Result<(Answer, Timings), SolveError> {
+    if part == 0 || part > S::PARTS {
+        return Err(SolveError::NoSuchPart{part: part, parts: S::PARTS});
+    }
+    let start = Instant::now();
+    let input = S::parse(data)?;
+    let parsed = Instant::now();
+
+    let answer = match part {
+        1 => S::part1(&input),
+        _ => S::part2(&input),
+    }.map_err(|e| SolveError::NoAnswer{part: part, error: e})?;
+    return Ok((answer, Timings{parse: parsed - start, solve: parsed.elapsed()}));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day25::Day25;
+
+    #[test]
+    fn rejects_missing_parts() {
+        let data = include_str!("../Day25/sample_input");
+        assert_eq!(solve::<Day25>(data, 2), Err(SolveError::NoSuchPart{part: 2, parts: 1}));
+        assert_eq!(solve::<Day25>(data, 0).unwrap_err().to_string(), "There is no part 0, the day has 1");
+        assert_eq!(Day25::part2(&Day25::parse(data).unwrap()), Err(NoAnswer::new(25, "Day 25 only has one part")));
+    }
+}
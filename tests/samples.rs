@@ -0,0 +1,44 @@
+// Explicit returns are the house style
+#![allow(clippy::needless_return)]
+
+// Runs every solution against the inputs shipped with it and compares the
+// answers to the ones recorded in answers.toml
+use aoc_2021_solutions::answers::{self, Manifest, Verdict, Verification};
+use aoc_2021_solutions::input::InputSource;
+use aoc_2021_solutions::registry::{self, Entry};
+
+fn describe(results: &[Verification]) -> String {
+    return results.iter().map(|r| format!("day {} part {}: {:?}", r.day, r.part, r.verdict))
+                         .collect::<Vec<String>>()
+                         .join("\n");
+}
+
+// Verifies every day whose input is present on disk
+fn verify_present(source: &InputSource) -> Vec<Verification> {
+    let manifest = Manifest::load(answers::DEFAULT_PATH).unwrap_or_else(|e| panic!("{}", e));
+    let entries: Vec<&Entry> = registry::SOLUTIONS.iter()
+                                                  .filter(|e| source.path(e.day).unwrap().exists())
+                                                  .collect();
+    return answers::verify(&manifest, source, &entries);
+}
+
+#[test]
+fn sample_inputs() {
+    let results = verify_present(&InputSource::Sample);
+    assert_eq!(results.len(), 49);
+
+    let regressions: Vec<Verification> = results.into_iter().filter(|r| !matches!(r.verdict, Verdict::Pass)).collect();
+    // The Day 24 sample is not a MONAD program, so it has no answers
+    assert!(regressions.iter().all(|r| r.day == 24 && matches!(r.verdict, Verdict::Unrecorded(_))),
+            "{}", describe(&regressions));
+}
+
+// The puzzle inputs are personal and slow to solve without optimisations, run
+// with `cargo test --release -- --ignored`. Days without an input are skipped.
+#[test]
+#[ignore]
+fn puzzle_inputs() {
+    let results = verify_present(&InputSource::Puzzle);
+    let regressions: Vec<Verification> = results.into_iter().filter(|r| r.is_regression()).collect();
+    assert!(regressions.is_empty(), "{}", describe(&regressions));
+}